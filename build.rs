@@ -0,0 +1,4 @@
+fn main() {
+    // exposes the Python version cfgs, such as Py_3_11, that gate the use of the buffer API
+    pyo3_build_config::use_pyo3_cfgs();
+}
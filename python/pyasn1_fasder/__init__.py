@@ -1,11 +1,36 @@
-from typing import Tuple
+from typing import Any, Callable, Dict, Iterable, List, Optional, Tuple, Union
 
 from pyasn1.type.base import Asn1Type
 
-from ._native import decode_der as native_decode_der
+from pyasn1.codec.der import encoder as der_encoder
 
+from ._native import (canonicalize_der as native_canonicalize_der, decode_ber as native_decode_ber,
+                      decode_cer as native_decode_cer, decode_der as native_decode_der,
+                      decode_der_native as native_decode_der_native, decode_der_path as native_decode_der_path,
+                      der_to_jer as native_der_to_jer, reset_helper_state as native_reset_helper_state,
+                      validate_der as native_validate_der)
+from ._native_helper import _TYPE_MAP
+from .error import DecodeWarning, IntegerRuleError, Pyasn1FasderError, RoundTripError, TlvLengthError
+from .finding import DerFinding
+from .lazy import LazyValue, _frame_components, _is_lazy_type
+from .position import ComponentPosition
+from .rule import IntegerRule
 
-def decode_der(substrate, asn1Spec: Asn1Type) -> Tuple[Asn1Type, bytes]:
+
+ComponentPath = Tuple[Union[str, int], ...]
+
+_BUILTIN_TYPE_IDS = frozenset(_TYPE_MAP)
+
+
+def decode_der(substrate, asn1Spec: Asn1Type, memoryviewValues: bool = False,
+               positions: Optional[Dict[ComponentPath, ComponentPosition]] = None,
+               verifyRoundTrip: bool = False, utcTimePivotYear: int = 1950,
+               opaqueComponents: Optional[Iterable[Union[ComponentPath, type, Asn1Type]]] = None,
+               integerRules: Optional[Dict[ComponentPath, IntegerRule]] = None,
+               nonEmptyComponents: Optional[Iterable[ComponentPath]] = None,
+               warningCallback: Optional[Callable[[DecodeWarning], None]] = None,
+               visitor: Optional[Callable[[ComponentPath, Asn1Type, Any, int, bytes], None]] = None
+               ) -> Tuple[Asn1Type, bytes]:
     """Decodes the specified substrate into an ASN.1 object with the specified schema object. Unlike the 'decode'
     functions in pyasn1, the schema object must be specified. Additionally, this function raises an exception if
     the substrate contains trailing octets. The second value of the tuple returned by this function is always an
@@ -15,13 +40,567 @@ def decode_der(substrate, asn1Spec: Asn1Type) -> Tuple[Asn1Type, bytes]:
     ----------
 
     substrate: object
-        This argument must return a byte string if passed to the 'bytes' function.
+        This argument must be a byte string or an object that supports the buffer protocol, such as a bytearray,
+        memoryview or mmap. Byte strings are decoded without being copied, as are read-only memoryviews over byte
+        strings if the extension is built for Python 3.11 or later; other buffers are copied once before decoding.
+    asn1Spec: object
+        This argument specifies the ASN.1 schema to use for decoding the substrate.
+    memoryviewValues: bool
+        If true, the values of decoded 'OctetString' and 'Any' objects are memoryview slices of the substrate rather
+        than copies of the value octets. The substrate must support the buffer protocol and must not be modified
+        while the decoded objects are in use. Such objects are instances of a subclass of the type of their schema
+        object, which is named after the type with a 'View' suffix.
+    positions: dict
+        If specified, this dictionary is populated with the position of every decoded component within the
+        substrate. Keys are component paths, which are tuples of component names (for 'Sequence' components and
+        'Choice' alternatives) and indexes (for 'SequenceOf' and 'SetOf' components). The path of the outermost
+        component is the empty tuple.
+    verifyRoundTrip: bool
+        If true, the decoded object is re-encoded with the pyasn1 DER encoder and compared with the substrate. This
+        detects schemas and custom decoders that decode values that differ from those that were encoded. The pyasn1
+        DER encoder must support the types of all decoded objects.
+    utcTimePivotYear: int
+        Decoded 'UTCTime' and 'GeneralizedTime' objects have a 'parsedDateTime' attribute, which is the value as a
+        timezone-aware datetime object in UTC. Fractional seconds of 'GeneralizedTime' values are preserved with
+        microsecond precision. Two-digit 'UTCTime' years are mapped onto the century that starts with this year, which
+        by default is 1950 as specified in RFC 5280.
+    opaqueComponents: iterable
+        If specified, the components at these component paths and the values of these pyasn1 types (specified as
+        classes or schema objects) are returned as 'Any' objects that hold the octets of their TLVs, including any
+        explicit tags, rather than being decoded. Their tags are matched against the schema and the framing of their
+        nested TLVs is checked, but their values are not.
+    integerRules: dict
+        If specified, the 'Integer' and 'Enumerated' values of the components at the component paths that are keys of
+        this dictionary must satisfy the 'IntegerRule' objects that they are mapped to. This allows a profile to limit
+        the size and sign of values, such as the 20-octet limit that RFC 5280 imposes on serial numbers, without
+        changing the schema.
+    nonEmptyComponents: iterable
+        If specified, empty 'SequenceOf' and 'SetOf' values at these component paths, where a profile expects
+        content, are reported as warnings.
+    warningCallback: callable
+        Values that are suspicious but do not violate the encoding rules, such as values that violate integer rules
+        with 'warn' set, empty 'SequenceOf' and 'SetOf' values of 'nonEmptyComponents' and 'UTCTime' values after
+        2049, are reported as 'DecodeWarning' objects.
+        If this argument is specified, it is called with every warning; otherwise, the warnings are issued through
+        the 'warnings' module.
+    visitor: callable
+        If specified, this callable is invoked for every component as soon as it is decoded, so components are
+        visited before the components that enclose them. It is called with the component path, the schema object of
+        the component, the decoded component, the substrate offset of the component and the octets of its TLV,
+        including any explicit tags. If the visitor raises an exception, decoding is aborted and the exception is
+        raised as the cause of a 'PyAsn1Error' that identifies the component.
+
+    Raises
+    ------
+    PyAsn1Error
+        If a decoding error occurs or the visitor raises an exception.
+    IntegerRuleError
+        If a value violates its integer rule.
+    ValueError
+        If the UTCTime pivot year is not between 1 and 9900.
+    RoundTripError
+        If round-trip verification is enabled and the re-encoded object differs from the substrate.
+    """
+    if verifyRoundTrip and positions is None:
+        positions = {}
+
+    decoded = native_decode_der(substrate, asn1Spec, memoryview_values=memoryviewValues, positions=positions,
+                                utc_time_pivot_year=utcTimePivotYear, **_get_opaque_kwargs(opaqueComponents),
+                                **_get_integer_rules_kwargs(integerRules),
+                                **_get_non_empty_kwargs(nonEmptyComponents),
+                                warning_callback=warningCallback, visitor=visitor)
+
+    if verifyRoundTrip:
+        _verify_round_trip(substrate, decoded, positions)
+
+    return decoded, b''
+
+
+def _get_opaque_kwargs(opaqueComponents) -> Dict[str, Any]:
+    if opaqueComponents is None:
+        return {}
+
+    opaque_components = []
+
+    # component paths are passed as tuples and pyasn1 types as classes
+    for component in opaqueComponents:
+        if isinstance(component, tuple) or (isinstance(component, type) and issubclass(component, Asn1Type)):
+            opaque_components.append(component)
+        elif isinstance(component, Asn1Type):
+            opaque_components.append(type(component))
+        else:
+            raise TypeError('Opaque components must be component paths or pyasn1 types')
+
+    return {'opaque_components': opaque_components}
+
+
+def _get_integer_rules_kwargs(integerRules) -> Dict[str, Any]:
+    if integerRules is None:
+        return {}
+
+    integer_rules = {}
+
+    for path, rule in integerRules.items():
+        if not isinstance(rule, IntegerRule):
+            raise TypeError('Integer rules must be IntegerRule objects')
+
+        integer_rules[tuple(path)] = rule
+
+    return {'integer_rules': integer_rules}
+
+
+def _get_non_empty_kwargs(nonEmptyComponents) -> Dict[str, Any]:
+    if nonEmptyComponents is None:
+        return {}
+
+    return {'non_empty_components': {tuple(p) for p in nonEmptyComponents}}
+
+
+def _find_first_difference(substrate, encoded) -> Optional[int]:
+    if substrate == encoded:
+        return None
+
+    return next((i for i, (s, e) in enumerate(zip(substrate, encoded)) if s != e), min(len(substrate), len(encoded)))
+
+
+def _verify_round_trip(substrate, decoded: Asn1Type, positions: Dict[ComponentPath, ComponentPosition]) -> None:
+    substrate = memoryview(substrate).cast('B')
+
+    if _find_first_difference(substrate, der_encoder.encode(decoded)) is None:
+        return
+
+    differences = {}
+
+    for path, position in positions.items():
+        component = decoded
+
+        for segment in path:
+            component = component[segment]
+
+        difference = _find_first_difference(substrate[position.tlv_range], der_encoder.encode(component))
+
+        if difference is not None:
+            differences[path] = position.offset + difference
+
+    # a difference in a component changes the encodings of the enclosing components, so the difference is attributed
+    # to the first component that differs while its own components do not
+    innermost_paths = [p for p in differences if not any(len(o) > len(p) and o[:len(p)] == p for o in differences)]
+
+    path = min(innermost_paths, key=lambda p: differences[p])
+
+    raise RoundTripError(differences[path], path)
+
+
+def decode_ber(substrate, asn1Spec: Asn1Type, memoryviewValues: bool = False,
+               positions: Optional[Dict[ComponentPath, ComponentPosition]] = None, utcTimePivotYear: int = 1950,
+               opaqueComponents: Optional[Iterable[Union[ComponentPath, type, Asn1Type]]] = None,
+               integerRules: Optional[Dict[ComponentPath, IntegerRule]] = None,
+               nonEmptyComponents: Optional[Iterable[ComponentPath]] = None,
+               warningCallback: Optional[Callable[[DecodeWarning], None]] = None
+               ) -> Tuple[Asn1Type, List[DerFinding]]:
+    """Decodes the specified BER substrate into an ASN.1 object with the specified schema object. In addition to
+    DER, this function accepts indefinite-length and non-minimal length encodings, constructed encodings of string
+    types and the other encodings that BER permits but DER does not. Every place where the substrate deviates from DER
+    is reported. The decoded objects are identical to those returned by 'decode_der' for the DER encoding of the same
+    value.
+
+    Parameters
+    ----------
+
+    substrate: object
+        This argument must be a byte string or an object that supports the buffer protocol.
+    asn1Spec: object
+        This argument specifies the ASN.1 schema to use for decoding the substrate.
+    memoryviewValues: bool
+        This argument has the same meaning as for 'decode_der'. Values of constructed 'OctetString' encodings are
+        always copied.
+    positions: dict
+        This argument has the same meaning as for 'decode_der'. The value length of an indefinite-length encoding
+        includes the end-of-contents octets.
+    utcTimePivotYear: int
+        This argument has the same meaning as for 'decode_der'. Times that are not in the DER form, such as those with
+        local time differentials, are reported as deviations and their 'parsedDateTime' attribute is None.
+    opaqueComponents: iterable
+        This argument has the same meaning as for 'decode_der'. Deviations from DER in the length octets of nested
+        TLVs of opaque components are reported.
+    integerRules: dict
+        This argument has the same meaning as for 'decode_der'.
+    nonEmptyComponents: iterable
+        This argument has the same meaning as for 'decode_der'.
+    warningCallback: callable
+        This argument has the same meaning as for 'decode_der'.
+
+    Returns
+    -------
+    tuple
+        The decoded object and the list of deviations from DER, in the order in which they were found. The list is
+        empty if the substrate is valid DER.
+
+    Raises
+    ------
+    PyAsn1Error
+        If a decoding error occurs.
+    """
+    return native_decode_ber(substrate, asn1Spec, memoryview_values=memoryviewValues, positions=positions,
+                             utc_time_pivot_year=utcTimePivotYear, **_get_opaque_kwargs(opaqueComponents),
+                             **_get_integer_rules_kwargs(integerRules),
+                             **_get_non_empty_kwargs(nonEmptyComponents),
+                             warning_callback=warningCallback)
+
+
+def decode_cer(substrate, asn1Spec: Asn1Type, memoryviewValues: bool = False,
+               positions: Optional[Dict[ComponentPath, ComponentPosition]] = None, utcTimePivotYear: int = 1950,
+               opaqueComponents: Optional[Iterable[Union[ComponentPath, type, Asn1Type]]] = None,
+               integerRules: Optional[Dict[ComponentPath, IntegerRule]] = None,
+               nonEmptyComponents: Optional[Iterable[ComponentPath]] = None,
+               warningCallback: Optional[Callable[[DecodeWarning], None]] = None
+               ) -> Tuple[Asn1Type, bytes]:
+    """Decodes the specified CER substrate into an ASN.1 object with the specified schema object. Constructed values
+    must use indefinite-length encodings, and string values longer than 1000 octets must use constructed encodings
+    with 1000-octet segments. The checks that CER and DER have in common, such as canonical BOOLEAN values, zero BIT
+    STRING trailers and SET OF component ordering, are performed as for 'decode_der'. The second value of the tuple
+    returned by this function is always an empty byte string.
+
+    Parameters
+    ----------
+
+    substrate: object
+        This argument must be a byte string or an object that supports the buffer protocol.
+    asn1Spec: object
+        This argument specifies the ASN.1 schema to use for decoding the substrate.
+    memoryviewValues: bool
+        This argument has the same meaning as for 'decode_der'. Values of constructed 'OctetString' encodings are
+        always copied.
+    positions: dict
+        This argument has the same meaning as for 'decode_der'. The value length of an indefinite-length encoding
+        includes the end-of-contents octets.
+    utcTimePivotYear: int
+        This argument has the same meaning as for 'decode_der'.
+    opaqueComponents: iterable
+        This argument has the same meaning as for 'decode_der'.
+    integerRules: dict
+        This argument has the same meaning as for 'decode_der'.
+    nonEmptyComponents: iterable
+        This argument has the same meaning as for 'decode_der'.
+    warningCallback: callable
+        This argument has the same meaning as for 'decode_der'.
+
+    Raises
+    ------
+    PyAsn1Error
+        If a decoding error occurs.
+    """
+    return native_decode_cer(substrate, asn1Spec, memoryview_values=memoryviewValues, positions=positions,
+                             utc_time_pivot_year=utcTimePivotYear, **_get_opaque_kwargs(opaqueComponents),
+                             **_get_integer_rules_kwargs(integerRules),
+                             **_get_non_empty_kwargs(nonEmptyComponents),
+                             warning_callback=warningCallback), b''
+
+
+def decode_der_native(substrate, asn1Spec: Asn1Type, memoryviewValues: bool = False,
+                      positions: Optional[Dict[ComponentPath, ComponentPosition]] = None,
+                      utcTimePivotYear: int = 1950,
+                      opaqueComponents: Optional[Iterable[Union[ComponentPath, type, Asn1Type]]] = None,
+                      integerRules: Optional[Dict[ComponentPath, IntegerRule]] = None,
+                      nonEmptyComponents: Optional[Iterable[ComponentPath]] = None,
+                      warningCallback: Optional[Callable[[DecodeWarning], None]] = None) -> Any:
+    """Decodes the specified substrate with the specified schema object into plain Python objects rather than pyasn1
+    objects. The schema is walked and the substrate is checked as for 'decode_der'. Values are returned as follows:
+
+    - 'Sequence' and 'Set' values as dicts keyed by component name. Absent components with default values are
+      included, while other absent components are not.
+    - 'SequenceOf' and 'SetOf' values as lists.
+    - 'Choice' values as dicts with a single key, which is the name of the chosen alternative.
+    - 'Boolean' values as bools, and 'Integer' and 'Enumerated' values as ints.
+    - 'BitString' values as tuples of the value octets and the number of unused bits in the last octet.
+    - 'OctetString' and 'Any' values as byte strings.
+    - 'Null' values as None.
+    - 'ObjectIdentifier' values as dotted strings.
+    - 'UTCTime' and 'GeneralizedTime' values as timezone-aware datetime objects in UTC.
+    - Other character string values as strs.
+
+    Parameters
+    ----------
+
+    substrate: object
+        This argument must be a byte string or an object that supports the buffer protocol.
     asn1Spec: object
         This argument specifies the ASN.1 schema to use for decoding the substrate.
+    memoryviewValues: bool
+        If true, 'OctetString' and 'Any' values are returned as memoryview slices of the substrate rather than byte
+        strings. The substrate must support the buffer protocol and must not be modified while the values are in use.
+    positions: dict
+        This argument has the same meaning as for 'decode_der'.
+    utcTimePivotYear: int
+        Two-digit 'UTCTime' years are mapped onto the century that starts with this year, which by default is 1950.
+    opaqueComponents: iterable
+        This argument has the same meaning as for 'decode_der', except that opaque components are returned as byte
+        strings (or memoryview slices if 'memoryviewValues' is true) that hold the octets of their TLVs.
+    integerRules: dict
+        This argument has the same meaning as for 'decode_der'.
+    nonEmptyComponents: iterable
+        This argument has the same meaning as for 'decode_der'.
+    warningCallback: callable
+        This argument has the same meaning as for 'decode_der'.
 
     Raises
     ------
     PyAsn1Error
         If a decoding error occurs.
     """
-    return native_decode_der(bytes(substrate), asn1Spec), b''
+    return native_decode_der_native(substrate, asn1Spec, memoryview_values=memoryviewValues, positions=positions,
+                                    utc_time_pivot_year=utcTimePivotYear, **_get_opaque_kwargs(opaqueComponents),
+                                    **_get_integer_rules_kwargs(integerRules),
+                                    **_get_non_empty_kwargs(nonEmptyComponents),
+                                    warning_callback=warningCallback)
+
+
+def decode_der_path(substrate, asn1Spec: Asn1Type, path: ComponentPath, memoryviewValues: bool = False,
+                    utcTimePivotYear: int = 1950) -> Optional[Tuple[Asn1Type, Optional[ComponentPosition]]]:
+    """Decodes only the component at the specified path. The schema is used to navigate directly to the TLV of the
+    component, and the TLVs of the components that precede it are skipped by their lengths after their framing is
+    checked. The component itself is decoded and checked as by 'decode_der'. Components that follow the component and
+    the components of the enclosing values are not checked.
+
+    Parameters
+    ----------
+
+    substrate: object
+        This argument must be a byte string or an object that supports the buffer protocol.
+    asn1Spec: object
+        This argument specifies the ASN.1 schema of the substrate.
+    path: tuple
+        The path of the component, which consists of component names (for 'Sequence' components and 'Choice'
+        alternatives) and indexes (for 'SequenceOf' and 'SetOf' components), as for the 'positions' argument of
+        'decode_der'.
+    memoryviewValues: bool
+        This argument has the same meaning as for 'decode_der'.
+    utcTimePivotYear: int
+        This argument has the same meaning as for 'decode_der'.
+
+    Returns
+    -------
+    tuple
+        The decoded component and its position within the substrate, or None if the component is absent, which is
+        the case for optional components that are not present, indexes that are out of range and alternatives that
+        were not chosen. Absent components with default values are returned as their default values, as by
+        'decode_der', and their position is None.
+
+    Raises
+    ------
+    PyAsn1Error
+        If a decoding error occurs or the path does not denote a component of the schema.
+    """
+    return native_decode_der_path(substrate, asn1Spec, tuple(path), memoryview_values=memoryviewValues,
+                                  utc_time_pivot_year=utcTimePivotYear)
+
+
+def decode_der_lazy(substrate, asn1Spec: Asn1Type, utcTimePivotYear: int = 1950) -> Union[LazyValue, Asn1Type]:
+    """Decodes the specified substrate lazily. The components of 'Sequence', 'SequenceOf', 'SetOf' and 'Choice' values
+    are framed and decoded only when they are accessed through the returned 'LazyValue' object, so that large
+    structures can be inspected without building pyasn1 objects for the components that are not used. Encoding errors
+    in a component are raised when the component is accessed, and the 'decode' method of a 'LazyValue' decodes the
+    entire value as by 'decode_der'. Values of other types are decoded as by 'decode_der'.
+
+    Parameters
+    ----------
+
+    substrate: object
+        This argument must be a byte string or an object that supports the buffer protocol. Objects other than byte
+        strings are copied once.
+    asn1Spec: object
+        This argument specifies the ASN.1 schema to use for decoding the substrate.
+    utcTimePivotYear: int
+        This argument has the same meaning as for 'decode_der'.
+
+    Returns
+    -------
+    object
+        A 'LazyValue' for constructed values, otherwise the decoded value.
+
+    Raises
+    ------
+    PyAsn1Error
+        If the substrate does not contain a single TLV that matches the schema, the components of the outermost value
+        cannot be framed, or a decoding error occurs in a non-constructed value.
+    """
+    if not isinstance(substrate, bytes):
+        substrate = bytes(substrate)
+
+    if not _is_lazy_type(asn1Spec):
+        return native_decode_der(substrate, asn1Spec, utc_time_pivot_year=utcTimePivotYear)
+
+    # the components of the outermost value are framed eagerly so that the framing of the substrate is checked
+    position, component_positions = _frame_components(substrate, asn1Spec, 0, utcTimePivotYear)
+
+    if position.end != len(substrate):
+        raise Pyasn1FasderError(f'{len(substrate) - position.end} trailing octet(s) after TLV near substrate offset 0')
+
+    return LazyValue(substrate, asn1Spec, position, utcTimePivotYear, component_positions)
+
+
+def der_to_jer(substrate, asn1Spec: Asn1Type, octetsEncoding: str = 'hex') -> str:
+    """Decodes the specified substrate with the specified schema object and returns the JSON Encoding Rules (X.697)
+    encoding of the value as compact JSON text. The encoding is written while the substrate is decoded, without building
+    pyasn1 objects. The substrate is checked as for 'decode_der', but, as for 'decode_der_native', the subtype
+    constraints of the schema are not checked. Values are encoded as follows:
+
+    - 'Sequence' values as objects with a member for every component that is present in the substrate.
+    - 'SequenceOf' and 'SetOf' values as arrays.
+    - 'Choice' values as objects with a single member, which is named after the chosen alternative.
+    - 'Boolean' values as true or false, 'Integer' values as numbers and 'Null' values as null.
+    - 'Enumerated' values as their identifiers, or as numbers if the schema does not name the value.
+    - 'BitString' values as objects with a 'value' member, which contains the bits in hexadecimal padded with zero
+      bits to a multiple of eight, and a 'length' member, which contains the number of bits.
+    - 'OctetString' and 'Any' values as strings in hexadecimal or base64. The value of an 'Any' is its encoding.
+    - 'ObjectIdentifier' values as dotted strings, and time and other character string values as strings.
+
+    Parameters
+    ----------
+
+    substrate: object
+        This argument must be a byte string or an object that supports the buffer protocol.
+    asn1Spec: object
+        This argument specifies the ASN.1 schema to use for decoding the substrate.
+    octetsEncoding: str
+        The encoding of 'OctetString' and 'Any' values, which is either 'hex' or 'base64'.
+
+    Raises
+    ------
+    PyAsn1Error
+        If a decoding error occurs or a value has a type that cannot be encoded in JER.
+    ValueError
+        If the octets encoding is not supported.
+    """
+    return native_der_to_jer(substrate, asn1Spec, octets_encoding=octetsEncoding)
+
+
+def validate_der(substrate) -> List[DerFinding]:
+    """Checks the substrate for DER encoding errors without an ASN.1 schema. The TLV structure of the substrate is
+    walked and every TLV with a universal tag is checked against the DER rules for the type denoted by the tag, such as
+    minimal INTEGER encodings, canonical BOOLEAN values, BIT STRING trailers, OBJECT IDENTIFIER syntax, the alphabets
+    of restricted character strings and the ordering of SET components. TLVs with non-universal tags are only checked
+    for correct framing, as their types cannot be determined without a schema.
+
+    Parameters
+    ----------
+
+    substrate: object
+        This argument must be a byte string or an object that supports the buffer protocol. The substrate must
+        consist of exactly one TLV.
+
+    Returns
+    -------
+    list
+        The encoding errors that were found, in substrate order. An empty list is returned if no errors were found.
+    """
+    return native_validate_der(substrate)
+
+
+def canonicalize_der(substrate, asn1Spec: Optional[Asn1Type] = None) -> Tuple[bytes, List[DerFinding]]:
+    """Re-encodes the specified BER substrate in DER. Lengths are re-encoded in definite form with the minimum number
+    of octets, constructed strings are re-encoded as primitive strings, the components of SET OFs are sorted, BOOLEAN
+    and INTEGER values are re-encoded canonically and BIT STRING trailer bits are cleared.
+
+    If an ASN.1 schema is specified, the substrate is decoded with 'decode_ber' and the decoded object is re-encoded
+    with the pyasn1 DER encoder, which additionally omits components that are equal to their default value and removes
+    trailing zero bits from named BIT STRINGs. Implicitly tagged values are normalized according to their type. The
+    contents of 'Any' values are re-emitted as they were encoded.
+
+    If no schema is specified, only TLVs with universal tags are normalized according to their type, and the components
+    of every SET are sorted as those of a SET OF.
+
+    Parameters
+    ----------
+
+    substrate: object
+        This argument must be a byte string or an object that supports the buffer protocol. The substrate must
+        consist of exactly one TLV.
+    asn1Spec: object
+        This optional argument specifies the ASN.1 schema of the substrate.
+
+    Returns
+    -------
+    tuple
+        The DER encoding and the list of changes that were made, each identified by the offset of the changed TLV in
+        the substrate. The list is empty if the substrate is valid DER.
+
+    Raises
+    ------
+    PyAsn1Error
+        If the substrate is not valid BER, contains errors that cannot be corrected or, if no schema is specified,
+        contains TLVs that are nested more than 128 levels deep.
+    """
+    if asn1Spec is None:
+        return native_canonicalize_der(substrate)
+
+    decoded, changes = decode_ber(substrate, asn1Spec)
+
+    return der_encoder.encode(decoded), changes
+
+
+def register_decoder(asn1Type, decoder) -> None:
+    """Registers a decoder for the values of the specified pyasn1 type, which must have a 'typeId' that differs from
+    those of the types that are supported natively. The TLV of every value is read and matched against the tags of the
+    ASN.1 specification before the decoder is invoked.
+
+    Parameters
+    ----------
+
+    asn1Type: object
+        This argument specifies the pyasn1 type class or schema object whose values are decoded by the decoder.
+    decoder: object
+        If this argument is a pyasn1 type that is supported natively, the values are decoded with the native decoder
+        for that type. Otherwise, this argument must be a callable that accepts the schema object, the value octets of
+        the TLV as a byte string, the tag set of the TLV and the substrate offset of the TLV, and returns the decoded
+        object. Exceptions raised by the callable are chained to a Pyasn1FasderError that aborts decoding.
+
+    Raises
+    ------
+    ValueError
+        If the type is supported natively or the decoder is a pyasn1 type that is not supported natively.
+    """
+    type_id = asn1Type.typeId
+
+    if type_id in _BUILTIN_TYPE_IDS:
+        raise ValueError(f'Decoder for type ID {type_id} is built in and cannot be replaced')
+
+    if isinstance(decoder, type) and issubclass(decoder, Asn1Type):
+        if decoder.typeId not in _BUILTIN_TYPE_IDS:
+            raise ValueError(f'{decoder.__name__} does not have a native decoder')
+
+        _TYPE_MAP[type_id] = _TYPE_MAP[decoder.typeId]
+    else:
+        _TYPE_MAP[type_id] = decoder
+
+
+def unregister_decoder(asn1Type) -> None:
+    """Removes the decoder that was registered with 'register_decoder' for the values of the specified pyasn1 type.
+    Decoding values of the type fails afterwards, unless another decoder is registered.
+
+    Parameters
+    ----------
+
+    asn1Type: object
+        This argument specifies the pyasn1 type class or schema object whose decoder is removed.
+
+    Raises
+    ------
+    ValueError
+        If the type is supported natively or no decoder is registered for it.
+    """
+    type_id = asn1Type.typeId
+
+    if type_id in _BUILTIN_TYPE_IDS:
+        raise ValueError(f'Decoder for type ID {type_id} is built in and cannot be removed')
+
+    if _TYPE_MAP.pop(type_id, None) is None:
+        raise ValueError(f'No decoder is registered for type ID {type_id}')
+
+
+def reset_native_state() -> None:
+    """Clears the caches of tags, tag sets and OBJECT IDENTIFIER arcs that are shared between decoding calls, and looks
+    up the objects that the native module uses from its helper module again. The caches are bounded, so this is only
+    needed to release the memory that they hold or after the helper module has been modified.
+    """
+    native_reset_helper_state()
@@ -1,12 +1,31 @@
-from pyasn1.type import tag, tagmap
+import datetime
+import warnings
+
+from pyasn1.type import char, tag, tagmap, univ, useful
+
+from .error import DecodeWarning
+from .finding import DerFinding
+from .position import ComponentPosition
 
 _TAG_CLS = tag.Tag
 _TAGSET_CLS = tag.TagSet
 _TAGMAP_CLS = tagmap.TagMap
+_POSITION_CLS = ComponentPosition
+_FINDING_CLS = DerFinding
+_WARNING_CLS = DecodeWarning
+_DATETIME_CLS = datetime.datetime
+_UTC = datetime.timezone.utc
+_OPAQUE_SPEC = univ.Any()
 
 _TAG_CACHE = {}
 _TAGSET_CACHE = {}
 
+# the tag sets of explicitly tagged values, keyed by the tag set of their outer tags and the tag of the inner TLV
+_EXPLICIT_TAGSET_CACHE = {}
+
+# the arcs of OBJECT IDENTIFIERs, keyed by their value octets
+_OID_CACHE = {}
+
 _TYPE_MAP = {}
 
 for tag_id in range(1, 30):
@@ -33,3 +52,88 @@ _CHOICE_SET_COMPONENT_KWARGS = {
     **_CONSTRUCTED_SET_COMPONENT_KWARGS,
     "innerFlag": False,
 }
+
+
+class _BufferValue:
+    """Base of the classes of decoded 'OctetString' and 'Any' objects that hold memoryview slices of the substrate.
+    pyasn1 accesses the value through the '_value' attribute, which converts the slice into a byte string, so the
+    slice is only copied for the duration of an operation, such as printing or comparing the object.
+    """
+    def prettyIn(self, value):
+        # pyasn1 copies any other value into a new byte string
+        if isinstance(value, memoryview):
+            return value
+
+        return super().prettyIn(value)
+
+    @property
+    def _value(self):
+        # pyasn1 assigns instance attributes through the instance dictionary, which this property takes precedence over
+        value = self.__dict__['_value']
+
+        return bytes(value) if isinstance(value, memoryview) else value
+
+    def prettyPrint(self, scope=0):
+        # pyasn1 compares the pretty-printed value with the '_value' attribute by identity, so a copy is printed
+        if isinstance(self.__dict__['_value'], memoryview):
+            return self.clone(self._value).prettyPrint(scope)
+
+        return super().prettyPrint(scope)
+
+
+# the buffer-backed classes of pyasn1 types, keyed by the pyasn1 type
+_BUFFER_VALUE_CLASSES = {}
+
+
+def _get_buffer_value_class(cls):
+    buffer_cls = _BUFFER_VALUE_CLASSES.get(cls)
+
+    if buffer_cls is None:
+        # the class is named after the pyasn1 type so that it is not mistaken for that type
+        namespace = {'__qualname__': f'{cls.__qualname__}View', '__module__': __name__}
+
+        buffer_cls = _BUFFER_VALUE_CLASSES.setdefault(cls, type(f'{cls.__name__}View', (_BufferValue, cls), namespace))
+
+    return buffer_cls
+
+
+def _clone_with_buffer(asn1_spec, buffer):
+    # the value is created as pyasn1 clones the schema object, but with the buffer-backed subclass of its type
+    return _get_buffer_value_class(asn1_spec.__class__)(buffer, **asn1_spec.readOnly)
+
+
+def _issue_warning(warning):
+    # the warning is attributed to the caller of the public decoding function
+    warnings.warn(warning, stacklevel=3)
+
+
+def _to_native(value):
+    # converts pyasn1 objects, such as default values of components, into the values returned by 'decode_der_native'
+    if isinstance(value, univ.Boolean):
+        return bool(value)
+    elif isinstance(value, univ.Integer):
+        return int(value)
+    elif isinstance(value, univ.BitString):
+        unused_bits = -len(value) % 8
+
+        return (value.asInteger() << unused_bits).to_bytes((len(value) + unused_bits) // 8, 'big'), unused_bits
+    elif isinstance(value, univ.ObjectIdentifier):
+        return str(value)
+    elif isinstance(value, univ.Null):
+        return None
+    elif isinstance(value, (useful.UTCTime, useful.GeneralizedTime)):
+        return value.asDateTime.astimezone(_UTC)
+    elif isinstance(value, char.AbstractCharacterString):
+        return str(value)
+    elif isinstance(value, univ.OctetString):
+        return value.asOctets()
+    elif isinstance(value, (univ.SequenceOf, univ.SetOf)):
+        return [_to_native(v) for v in value]
+    elif isinstance(value, univ.Choice):
+        name = value.getName()
+
+        return {name: _to_native(value[name])}
+    elif isinstance(value, (univ.Sequence, univ.Set)):
+        return {k: _to_native(v) for k, v in value.items() if v.isValue}
+    else:
+        raise TypeError(f'{type(value).__name__} values cannot be converted')
@@ -4,3 +4,78 @@ from pyasn1 import error
 class Pyasn1FasderError(error.PyAsn1Error):
     def __init__(self, *args, **kwargs):
         super().__init__(*args, **kwargs)
+
+
+class RoundTripError(Pyasn1FasderError):
+    """Raised if a decoded value does not re-encode to the substrate that it was decoded from. The 'offset' attribute
+    is the offset of the first octet that differs, and the 'path' attribute is the path of the innermost component
+    whose TLV contains that octet.
+    """
+    def __init__(self, offset, path):
+        super().__init__(f'Re-encoded value differs from substrate at offset {offset} in component {path}')
+
+        self.offset = offset
+        self.path = path
+
+
+class TlvLengthError(Pyasn1FasderError):
+    """Raised if the length octets of a TLV header are invalid. The 'kind' attribute identifies the error:
+
+    'truncated'
+        The substrate ends before the length octets do.
+    'indefinite'
+        The length is encoded in indefinite form, which DER does not permit.
+    'non-minimal'
+        The length is encoded in long form with more octets than necessary, which DER does not permit.
+    'oversized'
+        The length is encoded with the reserved initial octet 0xFF or with more octets than are supported.
+    'exceeds-input'
+        The length exceeds the number of octets that follow the header.
+
+    The 'offset' attribute is the substrate offset of the TLV, the 'declared_length' attribute is the length that the
+    length octets declare or None if it cannot be determined, the 'available_length' attribute is the number of octets
+    that follow the length octets and the 'length_octets' attribute holds the length octets that are present, including
+    the initial length octet.
+    """
+    def __init__(self, message, kind, offset, declared_length, available_length, length_octets):
+        super().__init__(message)
+
+        # pyasn1 errors do not initialize Exception, so the exception arguments would otherwise be all of those above
+        self.args = (message,)
+
+        self.kind = kind
+        self.offset = offset
+        self.declared_length = declared_length
+        self.available_length = available_length
+        self.length_octets = bytes(length_octets)
+
+
+class IntegerRuleError(Pyasn1FasderError):
+    """Raised if an 'Integer' or 'Enumerated' value violates the 'IntegerRule' of its component. The 'description'
+    attribute describes the violation, the 'offset' attribute is the substrate offset of the TLV of the value and the
+    'path' attribute is the component path of the value.
+    """
+    def __init__(self, description, offset, path):
+        message = f'{description} near substrate offset {offset} in component {path}'
+
+        super().__init__(message)
+
+        # pyasn1 errors do not initialize Exception, so the exception arguments would otherwise be all of those above
+        self.args = (message,)
+
+        self.description = description
+        self.offset = offset
+        self.path = path
+
+
+class DecodeWarning(UserWarning):
+    """Issued for values that are suspicious but do not violate the encoding rules, such as negative serial numbers.
+    The 'description' attribute describes the finding, the 'offset' attribute is the substrate offset of the TLV of
+    the value and the 'path' attribute is the component path of the value.
+    """
+    def __init__(self, description, offset, path):
+        super().__init__(f'{description} near substrate offset {offset} in component {path}')
+
+        self.description = description
+        self.offset = offset
+        self.path = path
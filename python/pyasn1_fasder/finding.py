@@ -0,0 +1,7 @@
+from typing import NamedTuple
+
+
+class DerFinding(NamedTuple):
+    """A DER encoding error detected by 'validate_der' or a deviation from DER reported by 'decode_ber'."""
+    offset: int
+    description: str
@@ -0,0 +1,149 @@
+from typing import Any, Dict, Iterator, Optional, Tuple, Union
+
+from pyasn1.type.base import Asn1Type
+
+from ._native import decode_der_at, frame_der_components
+from ._native_helper import _TYPE_MAP
+from .position import ComponentPosition
+
+
+_DECODER_TYPE_SEQUENCE = 16
+_DECODER_TYPE_SETOF = 17
+_DECODER_TYPE_SEQUENCEOF = 32
+_DECODER_TYPE_CHOICE = 99
+
+_LAZY_DECODER_TYPES = frozenset({_DECODER_TYPE_SEQUENCE, _DECODER_TYPE_SETOF, _DECODER_TYPE_SEQUENCEOF,
+                                 _DECODER_TYPE_CHOICE})
+
+
+def _is_lazy_type(asn1Spec: Asn1Type) -> bool:
+    return _TYPE_MAP.get(asn1Spec.typeId) in _LAZY_DECODER_TYPES
+
+
+def _frame_components(substrate: bytes, asn1Spec: Asn1Type, offset: int,
+                      utcTimePivotYear: int) -> Tuple[ComponentPosition, Dict[int, ComponentPosition]]:
+    return frame_der_components(substrate, asn1Spec, offset, utc_time_pivot_year=utcTimePivotYear)
+
+
+class LazyValue:
+    """A constructed value whose components are decoded when they are accessed. The TLVs of the components are framed
+    the first time that any component is accessed, at which point the rules that concern the value as a whole are
+    checked. 'Sequence', 'SequenceOf', 'SetOf' and 'Choice' components are returned as 'LazyValue' objects, and all
+    other components are returned as decoded pyasn1 objects. Decoded components are cached.
+    """
+
+    def __init__(self, substrate: bytes, asn1Spec: Asn1Type, position: ComponentPosition, utcTimePivotYear: int,
+                 componentPositions: Optional[Dict[int, ComponentPosition]] = None):
+        self._substrate = substrate
+        self._asn1Spec = asn1Spec
+        self._position = position
+        self._utcTimePivotYear = utcTimePivotYear
+        self._decoderType = _TYPE_MAP[asn1Spec.typeId]
+        self._componentPositions = componentPositions
+        self._components: Dict[int, Any] = {}
+
+    @property
+    def asn1Spec(self) -> Asn1Type:
+        return self._asn1Spec
+
+    @property
+    def position(self) -> ComponentPosition:
+        return self._position
+
+    def decode(self) -> Asn1Type:
+        """Decodes the entire value as by 'decode_der'."""
+        return decode_der_at(self._substrate, self._asn1Spec, self._position.offset,
+                             utc_time_pivot_year=self._utcTimePivotYear)
+
+    def getName(self) -> str:
+        """Returns the name of the chosen alternative of a 'Choice' value."""
+        self._check_choice()
+
+        return self._asn1Spec.componentType.getNameByPosition(self._chosen_position())
+
+    def getComponent(self) -> Any:
+        """Returns the chosen alternative of a 'Choice' value."""
+        self._check_choice()
+
+        return self._get_component(self._chosen_position())
+
+    def __getitem__(self, key: Union[str, int]) -> Any:
+        if self._decoderType in {_DECODER_TYPE_SEQUENCEOF, _DECODER_TYPE_SETOF}:
+            if not isinstance(key, int):
+                raise TypeError('Components must be identified by index')
+
+            component_count = len(self._get_component_positions())
+
+            if not -component_count <= key < component_count:
+                raise IndexError(f'Component index {key} out of range')
+
+            return self._get_component(key % component_count)
+
+        if not isinstance(key, str):
+            raise TypeError('Components must be identified by name')
+
+        named_types = self._asn1Spec.componentType
+
+        try:
+            index = named_types.getPositionByName(key)
+        except Exception:
+            raise KeyError(key) from None
+
+        if index in self._get_component_positions():
+            return self._get_component(index)
+
+        # absent components with default values are returned as their default values
+        if self._decoderType == _DECODER_TYPE_SEQUENCE and named_types[index].isDefaulted:
+            return named_types[index].asn1Object
+
+        return None
+
+    def __len__(self) -> int:
+        return len(self._get_component_positions())
+
+    def __iter__(self) -> Iterator[Any]:
+        if self._decoderType in {_DECODER_TYPE_SEQUENCEOF, _DECODER_TYPE_SETOF}:
+            for index in self._get_component_positions():
+                yield self._get_component(index)
+        else:
+            # the names of the components that are present are iterated, as for the keys of a dict
+            for index in self._get_component_positions():
+                yield self._asn1Spec.componentType.getNameByPosition(index)
+
+    def __repr__(self) -> str:
+        return f'{self.__class__.__name__}({self._asn1Spec.__class__.__name__}, offset={self._position.offset})'
+
+    def _check_choice(self) -> None:
+        if self._decoderType != _DECODER_TYPE_CHOICE:
+            raise TypeError(f'{self._asn1Spec.__class__.__name__} value is not a Choice')
+
+    def _chosen_position(self) -> int:
+        return next(iter(self._get_component_positions()))
+
+    def _get_component_positions(self) -> Dict[int, ComponentPosition]:
+        if self._componentPositions is None:
+            _, self._componentPositions = _frame_components(self._substrate, self._asn1Spec, self._position.offset,
+                                                            self._utcTimePivotYear)
+
+        return self._componentPositions
+
+    def _get_component(self, key: int) -> Any:
+        if key in self._components:
+            return self._components[key]
+
+        position = self._get_component_positions()[key]
+
+        if self._decoderType in {_DECODER_TYPE_SEQUENCEOF, _DECODER_TYPE_SETOF}:
+            component_spec = self._asn1Spec.componentType
+        else:
+            component_spec = self._asn1Spec.componentType.getTypeByPosition(key)
+
+        if _is_lazy_type(component_spec):
+            component = LazyValue(self._substrate, component_spec, position, self._utcTimePivotYear)
+        else:
+            component = decode_der_at(self._substrate, component_spec, position.offset,
+                                      utc_time_pivot_year=self._utcTimePivotYear)
+
+        self._components[key] = component
+
+        return component
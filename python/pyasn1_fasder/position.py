@@ -0,0 +1,22 @@
+from typing import NamedTuple
+
+
+class ComponentPosition(NamedTuple):
+    """The location of the TLV of a decoded component within the substrate. For components with explicit tags, the
+    position spans the outermost explicit tag.
+    """
+    offset: int
+    header_length: int
+    value_length: int
+
+    @property
+    def value_offset(self) -> int:
+        return self.offset + self.header_length
+
+    @property
+    def end(self) -> int:
+        return self.value_offset + self.value_length
+
+    @property
+    def tlv_range(self) -> slice:
+        return slice(self.offset, self.end)
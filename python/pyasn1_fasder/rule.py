@@ -0,0 +1,15 @@
+from typing import NamedTuple, Optional
+
+
+class IntegerRule(NamedTuple):
+    """The size and sign limits that a profile imposes on an 'Integer' or 'Enumerated' component. The lengths are those
+    of the value octets, so a rule with a 'max_octets' of 20 accepts the positive serial numbers that RFC 5280 permits.
+    If 'positive' is true, the value must be greater than zero; if 'non_zero' is true, the value must not be zero. If
+    'warn' is true, violations are reported as 'DecodeWarning's rather than raising 'IntegerRuleError', which suits
+    limits that are commonly violated in practice, such as the RFC 5280 requirement for positive serial numbers.
+    """
+    min_octets: Optional[int] = None
+    max_octets: Optional[int] = None
+    positive: bool = False
+    non_zero: bool = False
+    warn: bool = False
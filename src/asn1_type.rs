@@ -1,25 +1,344 @@
 use crate::decoder::DecodeStep;
-use crate::{decode_der_rec, decode_explicit, decoder, get_chosen_spec, tag, NativeHelperModule, TAGSET_ATTR};
+use crate::path::PathSegment;
+use crate::tag::Asn1Tag;
+use crate::time::{self, Timestamp};
+use crate::{decode_der_rec, decode_explicit, decoder, get_chosen_spec, tag, IntegerRuleError, NativeHelperModule, TAGSET_ATTR};
 use der::asn1::{ObjectIdentifier, PrintableStringRef};
 use itertools::Itertools;
 use pyo3::prelude::{PyAnyMethods, PySetMethods};
-use pyo3::types::{IntoPyDict, PyBool, PyBytes, PyDict, PySet, PyString, PyTuple};
-use pyo3::{intern, Bound, IntoPy, PyAny, PyErr, PyResult};
+use pyo3::types::{IntoPyDict, PyBool, PyBytes, PyDict, PyList, PyListMethods, PySet, PyString, PyStringMethods, PyTuple};
+use pyo3::{intern, Bound, FromPyObject, IntoPyObject, PyAny, PyResult};
+use std::borrow::Cow;
 
 
 const CONSTRUCTED_SET_COMPONENT_KWARGS: &str = "_CONSTRUCTED_SET_COMPONENT_KWARGS";
 const CHOICE_SET_COMPONENT_KWARGS: &str = "_CHOICE_SET_COMPONENT_KWARGS";
+const CLONE_WITH_BUFFER: &str = "_clone_with_buffer";
+const TO_NATIVE: &str = "_to_native";
+const PARSED_DATETIME_ATTR: &str = "parsedDateTime";
+const DATETIME_CLS: &str = "_DATETIME_CLS";
+const UTC: &str = "_UTC";
 
+/// First year that RFC 5280 requires to be encoded as GeneralizedTime rather than UTCTime
+const UTC_TIME_END_YEAR: u16 = 2050;
 
-fn create_value_args<'py>(value: Bound<'py, PyAny>) -> Bound<'py, PyTuple> {
-    PyTuple::new_bound(value.py(), vec![value])
+
+fn create_value_args<'py>(value: Bound<'py, PyAny>) -> PyResult<Bound<'py, PyTuple>> {
+    PyTuple::new(value.py(), [value])
 }
 
 
-fn clone_asn1_schema_obj<'py>(asn1_schema_obj: &Bound<'py, PyAny>, args: Bound<PyTuple>, kwargs: Option<Bound<PyDict>>) -> PyResult<Bound<'py, PyAny>> {
+fn clone_asn1_schema_obj<'py>(asn1_schema_obj: &Bound<'py, PyAny>, args: Bound<'py, PyTuple>, kwargs: Option<Bound<'py, PyDict>>) -> PyResult<Bound<'py, PyAny>> {
     asn1_schema_obj.call_method(intern![asn1_schema_obj.py(), "clone"], args, kwargs.as_ref())
 }
 
+
+fn clone_asn1_schema_obj_with_value_octets<'py>(step: &DecodeStep<'py>, start: usize, octets: &[u8]) -> PyResult<Bound<'py, PyAny>> {
+    let py = step.asn1_spec().py();
+
+    match step.context().substrate_slice(start, start + octets.len())? {
+        Some(view) if step.context().native_values() => Ok(view),
+        Some(view) => {
+            let m = step.module();
+
+            m.module.getattr(intern![py, CLONE_WITH_BUFFER])?.call1((step.asn1_spec(), view))
+        },
+        None if step.context().native_values() => Ok(PyBytes::new(py, octets).into_any()),
+        None => clone_asn1_schema_obj(step.asn1_spec(), create_value_args(PyBytes::new(py, octets).into_any())?, None)
+    }
+}
+
+
+/// Converts a pyasn1 object, such as the default value of a component, into the plain Python object that is returned
+/// when decoding into native values.
+fn to_native_value<'py>(m: &NativeHelperModule<'py>, asn1_value: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyAny>> {
+    m.module.getattr(intern![asn1_value.py(), TO_NATIVE])?.call1((asn1_value,))
+}
+
+
+fn create_datetime<'py>(m: &NativeHelperModule<'py>, timestamp: &Timestamp) -> PyResult<Bound<'py, PyAny>> {
+    let py = m.module.py();
+
+    let datetime_cls = m.module.getattr(intern![py, DATETIME_CLS])?;
+
+    datetime_cls.call(
+        (timestamp.year, timestamp.month, timestamp.day, timestamp.hour, timestamp.minute, timestamp.second, timestamp.microsecond),
+        Some(&[(intern![py, "tzinfo"], m.module.getattr(intern![py, UTC])?)].into_py_dict(py)?)
+    )
+}
+
+
+/// Decodes the value octets of a character string into a str, using the character encoding of the type.
+fn decode_character_string(type_name: &str, value: &[u8]) -> Result<String, String> {
+    let invalid_character = || format!("Invalid character in {} value", type_name);
+
+    match type_name {
+        "UTF8STRING" => String::from_utf8(value.to_vec()).map_err(|_| invalid_character()),
+        "BMPSTRING" => {
+            if !value.len().is_multiple_of(2) {
+                return Err(invalid_character());
+            }
+
+            char::decode_utf16(value.chunks(2).map(|c| u16::from_be_bytes([c[0], c[1]]))).collect::<Result<String, _>>().map_err(|_| invalid_character())
+        },
+        "UNIVERSALSTRING" => {
+            if !value.len().is_multiple_of(4) {
+                return Err(invalid_character());
+            }
+
+            value.chunks(4).map(|c| char::from_u32(u32::from_be_bytes([c[0], c[1], c[2], c[3]])).ok_or_else(invalid_character)).collect()
+        },
+        "TELETEXSTRING" | "VIDEOTEXSTRING" | "GRAPHICSTRING" => Ok(value.iter().map(|c| char::from(*c)).collect()),
+        _ => {
+            if value.is_ascii() {
+                Ok(value.iter().map(|c| char::from(*c)).collect())
+            }
+            else {
+                Err(invalid_character())
+            }
+        }
+    }
+}
+
+/// Checks the value octets of a BOOLEAN.
+pub fn check_boolean_value(value: &[u8]) -> Result<(), String> {
+    match value.len() {
+        1 => {
+            if value[0] != 0 && value[0] != 0xFF {
+                Err("Non-canonical BOOLEAN encoding".to_string())
+            }
+            else {
+                Ok(())
+            }
+        }
+        l => Err(format!("Invalid BOOLEAN value length of {} octets", l))
+    }
+}
+
+
+/// Checks the value octets of an INTEGER or ENUMERATED.
+pub fn check_integer_value(value: &[u8], type_name: &str) -> Result<(), String> {
+    if value.is_empty() {
+        Err(format!("Substrate under-run in {} value", type_name))
+    }
+    else if value.len() >= 2 && ((value[0] == 0 && value[1] & 0x80 == 0) || (value[0] == 0xFF && value[1] & 0x80 != 0)) {
+        Err(format!("Non-minimal {} encoding", type_name))
+    }
+    else {
+        Ok(())
+    }
+}
+
+
+/// The size and sign limits that a profile imposes on the INTEGER value of a component. Rules are extracted from the
+/// attributes of IntegerRule objects.
+#[derive(FromPyObject)]
+pub struct IntegerRule {
+    min_octets: Option<usize>,
+    max_octets: Option<usize>,
+    positive: bool,
+    non_zero: bool,
+    /// Violations are reported as warnings rather than errors
+    warn: bool
+}
+
+impl IntegerRule {
+    /// Checks the specified minimally encoded INTEGER value octets against the rule.
+    pub fn check(&self, value: &[u8]) -> Result<(), String> {
+        let is_zero = value == [0];
+        let is_negative = value[0] & 0x80 != 0;
+
+        if let Some(min_octets) = self.min_octets {
+            if value.len() < min_octets {
+                return Err(format!("Value length of {} octets is less than the minimum of {} octets", value.len(), min_octets));
+            }
+        }
+
+        if let Some(max_octets) = self.max_octets {
+            if value.len() > max_octets {
+                return Err(format!("Value length of {} octets exceeds the maximum of {} octets", value.len(), max_octets));
+            }
+        }
+
+        if self.positive && (is_zero || is_negative) {
+            Err("Value is not positive".to_string())
+        }
+        else if self.non_zero && is_zero {
+            Err("Value is zero".to_string())
+        }
+        else {
+            Ok(())
+        }
+    }
+}
+
+
+/// Returns the value of the specified INTEGER value octets if it fits in an i64.
+fn integer_value_as_i64(value: &[u8]) -> Option<i64> {
+    if value.is_empty() || value.len() > 8 {
+        return None;
+    }
+
+    let sign_extension = if value[0] & 0x80 != 0 { -1 } else { 0 };
+
+    Some(value.iter().fold(sign_extension, |acc, o| (acc << 8) | i64::from(*o)))
+}
+
+
+/// Checks the value octets of a BIT STRING, including the initial octet that specifies the trailer length.
+pub fn check_bit_string_value(value: &[u8]) -> Result<(), String> {
+    if value.is_empty() {
+        return Err("Substrate under-run in BIT STRING".to_string());
+    }
+
+    let trailer_bit_count = value[0];
+
+    if trailer_bit_count > 7 || (value.len() == 1 && trailer_bit_count != 0) {
+        return Err(format!("Invalid trailer length of {} bits in BIT STRING", trailer_bit_count));
+    }
+
+    Ok(())
+}
+
+
+/// Checks that the trailer bits of a BIT STRING are zero, as required by DER. The value octets must have passed
+/// `check_bit_string_value`.
+pub fn check_bit_string_trailer(value: &[u8]) -> Result<(), String> {
+    if value[value.len() - 1] & ((1 << value[0]) - 1) != 0 {
+        Err("Non-zero trailer value in BIT STRING".to_string())
+    }
+    else {
+        Ok(())
+    }
+}
+
+
+/// Checks that the last bit of a named BIT STRING is set, as trailing zero bits must be removed. The value octets
+/// must have passed `check_bit_string_value`.
+pub fn check_named_bit_string_value(value: &[u8]) -> Result<(), String> {
+    if value.len() >= 2 && value[value.len() - 1] & (1 << value[0]) == 0 {
+        Err("Trailing zero bit in named BIT STRING".to_string())
+    }
+    else {
+        Ok(())
+    }
+}
+
+
+/// Returns the value octets of a BIT STRING with any trailing zero bits removed. The value octets must have passed
+/// `check_bit_string_value`.
+pub fn remove_trailing_zero_bits(value: &[u8]) -> Vec<u8> {
+    let data = &value[1..];
+    let trailer_mask = (1u8 << value[0]) - 1;
+
+    let bit_count = (0..data.len()).rev()
+        .find_map(|i| {
+            let octet = if i + 1 == data.len() { data[i] & !trailer_mask } else { data[i] };
+
+            (octet != 0).then(|| i * 8 + 8 - octet.trailing_zeros() as usize)
+        })
+        .unwrap_or(0);
+
+    let octet_count = bit_count.div_ceil(8);
+    let new_trailer_bit_count = (octet_count * 8 - bit_count) as u8;
+
+    let mut octets = vec![new_trailer_bit_count];
+    octets.extend_from_slice(&data[..octet_count]);
+
+    // clear any trailer bits that were set in the BER encoding
+    if octet_count > 0 {
+        octets[octet_count] &= !((1u8 << new_trailer_bit_count) - 1);
+    }
+
+    octets
+}
+
+
+/// Checks the value octets of a NULL.
+pub fn check_null_value(value: &[u8]) -> Result<(), String> {
+    match value.len() {
+        0 => Ok(()),
+        _ => Err("Invalid NULL value length".to_string())
+    }
+}
+
+
+/// Checks the value octets of an OBJECT IDENTIFIER.
+pub fn check_object_identifier_value(value: &[u8]) -> Result<(), String> {
+    match ObjectIdentifier::from_bytes(value) {
+        Ok(_) => Ok(()),
+        Err(e) => Err(e.to_string())
+    }
+}
+
+
+/// Checks the value octets of a PRINTABLESTRING.
+pub fn check_printable_string_value(value: &[u8]) -> Result<(), String> {
+    match PrintableStringRef::new(value) {
+        Ok(_) => Ok(()),
+        Err(e) => Err(format!("Error decoding PRINTABLESTRING: {}", e))
+    }
+}
+
+
+/// Checks that every character of a restricted character string is within the alphabet of the type denoted by the
+/// specified universal tag number. Types with unrestricted alphabets are not checked.
+pub fn check_character_string_alphabet(tag_id: u8, value: &[u8]) -> Result<(), String> {
+    let type_name = tag::universal_type_name(tag_id).unwrap_or("character string");
+
+    let is_valid = match tag_id {
+        12 => std::str::from_utf8(value).is_ok(),
+        18 => value.iter().all(|c| c.is_ascii_digit() || *c == b' '),
+        19 => return check_printable_string_value(value),
+        22 => value.is_ascii(),
+        26 => value.iter().all(|c| (0x20..=0x7E).contains(c)),
+        28 => value.len().is_multiple_of(4) && value.chunks(4).all(|c| char::from_u32(u32::from_be_bytes([c[0], c[1], c[2], c[3]])).is_some()),
+        30 => value.len().is_multiple_of(2) && value.chunks(2).all(|c| char::from_u32(u32::from(u16::from_be_bytes([c[0], c[1]]))).is_some()),
+        _ => true
+    };
+
+    if is_valid {
+        Ok(())
+    }
+    else {
+        Err(format!("Invalid character in {} value", type_name))
+    }
+}
+
+
+/// Checks a TLV with a universal tag against the DER rules for the type denoted by the tag, without requiring an
+/// ASN.1 specification. Constructed values are only checked for the correct format.
+pub fn check_universal_tlv(tag: Asn1Tag, value: &[u8]) -> Result<(), String> {
+    let tag_id = tag.tag_id();
+
+    let type_name = match tag::universal_type_name(tag_id) {
+        None => return Err(format!("Reserved universal tag number {}", tag_id)),
+        Some(n) => n
+    };
+
+    // EXTERNAL, EMBEDDED PDV and CHARACTER STRING are defined in terms of SEQUENCE
+    let expected_format = match tag_id {
+        8 | 11 | tag::TAG_ID_SEQUENCE | tag::TAG_ID_SET | 29 => tag::FORMAT_CONSTRUCTED,
+        _ => tag::FORMAT_SIMPLE
+    };
+
+    if tag.format() != expected_format {
+        return Err(format!("Invalid {} value format", type_name));
+    }
+
+    match tag_id {
+        1 => check_boolean_value(value),
+        2 | 10 => check_integer_value(value, type_name),
+        3 => check_bit_string_value(value).and_then(|_| check_bit_string_trailer(value)),
+        5 => check_null_value(value),
+        6 => check_object_identifier_value(value),
+        23 => time::parse_utc_time(value, time::DEFAULT_UTC_TIME_PIVOT_YEAR).map(|_| ()),
+        24 => time::parse_generalized_time(value).map(|_| ()),
+        _ => check_character_string_alphabet(tag_id, value)
+    }
+}
+
+
 pub trait Decoder<'a, 'py> {
     fn verify_raw(self: &'a Self) -> PyResult<()> {
         Ok(())
@@ -49,30 +368,29 @@ impl<'a, 'py> Decoder<'a, 'py> for BooleanDecoder<'py> {
             return Err(self.step.create_error("Invalid BOOLEAN value format"));
         }
 
-        match self.step.value_substrate_len() {
-            1 => {
-                if self.step.value_substrate()[0] != 0 && self.step.value_substrate()[0] != 0xFF {
-                    Err(self.step.create_error("Non-canonical BOOLEAN encoding"))
-                }
-                else {
-                    Ok(())
-                }
-            }
-            l => Err(self.step.create_error(&format!("Invalid BOOLEAN value length of {} octets", l)))
+        match check_boolean_value(self.step.value_substrate()) {
+            Ok(()) => Ok(()),
+            // BER permits any non-zero value for TRUE
+            Err(e) if self.step.value_substrate_len() == 1 => self.step.canonical_violation(&e),
+            Err(e) => Err(self.step.create_error(&e))
         }
     }
 
     fn decode(&'a self) -> PyResult<Bound<'py, PyAny>> where 'py: 'a {
         let py = self.step.asn1_spec().py();
 
+        if self.step.context().native_values() {
+            return Ok(PyBool::new(py, self.step.value_substrate()[0] != 0).to_owned().into_any());
+        }
+
         let mapped_int_bool: u8 = match self.step.value_substrate()[0] {
             0 => 0,
             _ => 1,
         };
 
-        let py_value = mapped_int_bool.into_py(py).bind(py).clone();
+        let py_value = mapped_int_bool.into_pyobject(py)?.into_any();
 
-        clone_asn1_schema_obj(self.step.asn1_spec(), create_value_args(py_value), None)
+        clone_asn1_schema_obj(self.step.asn1_spec(), create_value_args(py_value)?, None)
     }
 }
 
@@ -94,14 +412,18 @@ impl<'a, 'py> Decoder<'a, 'py> for IntegerDecoder<'py> {
             return Err(self.step.create_error(&format!("Invalid {} value format", self.type_name)));
         }
 
-        let value_substrate = self.step.value_substrate();
+        check_integer_value(self.step.value_substrate(), self.type_name).map_err(|e| self.step.create_error(&e))?;
 
-        if value_substrate.len() == 0 {
-            return Err(self.step.create_error(&format!("Substrate under-run in {} value", self.type_name)))
-        }
-        else if value_substrate.len() >= 2 {
-            if (value_substrate[0] == 0 && value_substrate[1] & 0x80 == 0) || (value_substrate[0] == 0xFF && value_substrate[1] & 0x80 != 0) {
-                return Err(self.step.create_error(&format!("Non-minimal {} encoding", self.type_name)))
+        if let Some(rule) = self.step.context().integer_rule(self.step.path())? {
+            if let Err(e) = rule.check(self.step.value_substrate()) {
+                let py = self.step.asn1_spec().py();
+
+                // profiles may tolerate violations that are common in practice, such as negative serial numbers
+                if rule.warn {
+                    return self.step.warn(&e);
+                }
+
+                return Err(IntegerRuleError::new_err((e, self.step.offset(), self.step.path().to_py_tuple(py)?.unbind())));
             }
         }
 
@@ -111,10 +433,20 @@ impl<'a, 'py> Decoder<'a, 'py> for IntegerDecoder<'py> {
     fn decode(self: &'a Self) -> PyResult<Bound<'py, PyAny>> where 'py: 'a {
         let py = self.step.asn1_spec().py();
 
-        let binding = num_bigint::BigInt::from_signed_bytes_be(self.step.value_substrate()).into_py(py);
-        let py_value = binding.bind(py).clone();
+        let value_substrate = self.step.value_substrate();
+
+        // values that fit in an i64 are converted without allocating a BigInt
+        let py_value = match integer_value_as_i64(value_substrate) {
+            Some(v) => v.into_pyobject(py)?.into_any(),
+            None => num_bigint::BigInt::from_signed_bytes_be(value_substrate).into_pyobject(py)?.into_any()
+        };
+
+        if self.step.context().native_values() {
+            return Ok(py_value);
+        }
 
-        clone_asn1_schema_obj(self.step.asn1_spec(), create_value_args(py_value), None)
+        // the decoded objects are not cached, even for common small values, as callers may modify them
+        clone_asn1_schema_obj(self.step.asn1_spec(), create_value_args(py_value)?, None)
     }
 }
 
@@ -126,51 +458,20 @@ impl<'py> BitStringDecoder<'py> {
     pub(crate) fn new(step: DecodeStep<'py>) -> Self {
         Self { step }
     }
-
-    fn check_named_bit_string(self: &Self, trailer_bit_count: u8, last_octet: u8) -> PyResult<()> {
-        let last_value_bit_mask = 1 << trailer_bit_count;
-
-        if last_value_bit_mask & last_octet == 0 {
-            Err(self.step.create_error("Trailing zero bit in named BIT STRING"))
-        }
-        else {
-            Ok(())
-        }
-    }
 }
 
 impl<'a, 'py> Decoder<'a, 'py> for BitStringDecoder<'py> {
     fn verify_raw(self: &Self) -> PyResult<()> {
-        if self.step.tag().format() != tag::FORMAT_SIMPLE {
-            return Err(self.step.create_error("Invalid BIT STRING value format"));
-        }
+        self.step.check_string_encoding("BIT STRING")?;
 
-        let value_substrate = self.step.value_substrate();
-
-        let value_substrate_len = value_substrate.len();
+        let value_substrate = self.step.string_value_octets()?;
 
-        if value_substrate_len == 0 {
-            return Err(self.step.create_error("Substrate under-run in BIT STRING"));
-        }
+        check_bit_string_value(&value_substrate).map_err(|e| self.step.create_error(&e))?;
 
-        let trailer_bit_count = value_substrate[0];
+        check_bit_string_trailer(&value_substrate).or_else(|e| self.step.canonical_violation(&e))?;
 
-        if trailer_bit_count > 7 || (value_substrate_len == 1 && trailer_bit_count != 0) {
-            return Err(self.step.create_error(&format!("Invalid trailer length of {} bits in BIT STRING", trailer_bit_count)));
-        }
-
-        if value_substrate_len >= 2 {
-            let trailer_bits = value_substrate[value_substrate_len - 1] & ((1 << trailer_bit_count) - 1);
-
-            if trailer_bits != 0 {
-                return Err(self.step.create_error("Non-zero trailer value in BIT STRING"));
-            }
-
-            if self.step.asn1_spec().getattr(intern![self.step.asn1_spec().py(), "namedValues"])?.is_truthy()? {
-                let last_octet = value_substrate[value_substrate_len - 1];
-
-                return self.check_named_bit_string(trailer_bit_count, last_octet)
-            }
+        if value_substrate.len() >= 2 && self.step.asn1_spec().getattr(intern![self.step.asn1_spec().py(), "namedValues"])?.is_truthy()? {
+            return check_named_bit_string_value(&value_substrate).or_else(|e| self.step.canonical_violation(&e))
         }
 
         Ok(())
@@ -179,16 +480,27 @@ impl<'a, 'py> Decoder<'a, 'py> for BitStringDecoder<'py> {
     fn decode(self: &'a Self) -> PyResult<Bound<'py, PyAny>> where 'py: 'a {
         let py = self.step.asn1_spec().py();
 
+        let mut value_substrate = self.step.string_value_octets()?;
+
+        // trailing zero bits of named BIT STRINGs, which are only accepted when decoding BER, are not significant
+        if self.step.asn1_spec().getattr(intern![py, "namedValues"])?.is_truthy()? {
+            value_substrate = Cow::Owned(remove_trailing_zero_bits(&value_substrate));
+        }
+
+        if self.step.context().native_values() {
+            return Ok((PyBytes::new(py, &value_substrate[1..]), value_substrate[0]).into_pyobject(py)?.into_any());
+        }
+
         let value = self.step.asn1_spec().call_method(
-            intern![py, "fromOctetString"], create_value_args(PyBytes::new_bound(py, &self.step.value_substrate()[1..]).into_any()),
+            intern![py, "fromOctetString"], create_value_args(PyBytes::new(py, &value_substrate[1..]).into_any())?,
             Some(&[
-                (intern![py, "internalFormat"], PyBool::new_bound(py, true).as_any()),
-                (intern![py, "padding"], self.step.value_substrate()[0].into_py(py).bind(py))]
-                .into_py_dict_bound(py)
+                (intern![py, "internalFormat"], PyBool::new(py, true).as_any()),
+                (intern![py, "padding"], &value_substrate[0].into_pyobject(py)?.into_any())]
+                .into_py_dict(py)?
                 )
         )?;
 
-        clone_asn1_schema_obj(self.step.asn1_spec(), create_value_args(value), None)
+        clone_asn1_schema_obj(self.step.asn1_spec(), create_value_args(value)?, None)
     }
 }
 
@@ -207,16 +519,20 @@ impl<'py> OctetStringDecoder<'py> {
 impl<'a, 'py> Decoder<'a, 'py> for OctetStringDecoder<'py> {
 
     fn verify_raw(self: &Self) -> PyResult<()> {
-        match self.step.tag().format() {
-            tag::FORMAT_SIMPLE => Ok(()),
-            _ => Err(self.step.create_error("Invalid OCTET STRING value format"))
-        }
+        self.step.check_string_encoding("OCTET STRING")
     }
 
     fn decode(self: &'a Self) -> PyResult<Bound<'py, PyAny>> where 'py: 'a {
-        let py = self.step.asn1_spec().py();
-
-        clone_asn1_schema_obj(self.step.asn1_spec(), create_value_args(PyBytes::new_bound(py, self.step.value_substrate()).into_any()), None)
+        match self.step.string_value_octets()? {
+            Cow::Borrowed(b) => clone_asn1_schema_obj_with_value_octets(&self.step, self.step.offset() + self.step.header_len(), b),
+            // the value of a constructed encoding is not contiguous in the substrate, so it cannot be a memoryview slice
+            Cow::Owned(o) if self.step.context().native_values() => Ok(PyBytes::new(self.step.asn1_spec().py(), &o).into_any()),
+            Cow::Owned(o) => {
+                let py = self.step.asn1_spec().py();
+
+                clone_asn1_schema_obj(self.step.asn1_spec(), create_value_args(PyBytes::new(py, &o).into_any())?, None)
+            }
+        }
     }
 }
 
@@ -237,16 +553,17 @@ impl<'a, 'py> Decoder<'a, 'py> for NullDecoder<'py> {
             return Err(self.step.create_error("Invalid NULL value format"))
         }
 
-        match self.step.value_substrate_len() {
-            0 => Ok(()),
-            _ => Err(self.step.create_error("Invalid NULL value length"))
-        }
+        check_null_value(self.step.value_substrate()).map_err(|e| self.step.create_error(&e))
     }
 
     fn decode(self: &'a Self) -> PyResult<Bound<'py, PyAny>> where 'py: 'a {
         let py = self.step.asn1_spec().py();
 
-        clone_asn1_schema_obj(self.step.asn1_spec(), create_value_args(PyString::new_bound(py, "").into_any()), None)
+        if self.step.context().native_values() {
+            return Ok(py.None().into_bound(py));
+        }
+
+        clone_asn1_schema_obj(self.step.asn1_spec(), create_value_args(PyString::new(py, "").into_any())?, None)
     }
 }
 
@@ -272,15 +589,18 @@ impl<'a, 'py> Decoder<'a, 'py> for ObjectIdentifierDecoder<'py> {
     fn decode(self: &'a Self) -> PyResult<Bound<'py, PyAny>> where 'py: 'a {
         let py = self.step.asn1_spec().py();
 
-        match ObjectIdentifier::from_bytes(self.step.value_substrate()) {
-            Ok(oid) => {
-                clone_asn1_schema_obj(
-                    self.step.asn1_spec(),
-                    create_value_args(PyTuple::new_bound(py, oid.arcs().collect_vec()).into_any()),
-                    None)
-            }
-            Err(e) => Err(self.step.create_error(&e.to_string()))
+        let parse = || ObjectIdentifier::from_bytes(self.step.value_substrate()).map_err(|e| self.step.create_error(&e.to_string()));
+
+        if self.step.context().native_values() {
+            return Ok(PyString::new(py, &parse()?.to_string()).into_any());
         }
+
+        let m = self.step.module();
+        let value_octets = PyBytes::new(py, self.step.value_substrate());
+
+        let arcs = m.get_oid_arcs(&value_octets, || Ok(PyTuple::new(py, parse()?.arcs().collect_vec())?.into_any()))?;
+
+        clone_asn1_schema_obj(self.step.asn1_spec(), create_value_args(arcs)?, None)
     }
 }
 
@@ -298,16 +618,78 @@ impl<'py> CharacterStringDecoder<'py> {
 
 impl<'a, 'py> Decoder<'a, 'py> for CharacterStringDecoder<'py> {
     fn verify_raw(self: &Self) -> PyResult<()> {
-        match self.step.tag().format() {
-            tag::FORMAT_SIMPLE => Ok(()),
-            _ => Err(self.step.create_error(&format!("Invalid {} value format", self.type_name)))
-        }
+        self.step.check_string_encoding(self.type_name)
     }
 
     fn decode(self: &'a Self) -> PyResult<Bound<'py, PyAny>> where 'py: 'a {
         let py = self.step.asn1_spec().py();
 
-        clone_asn1_schema_obj(self.step.asn1_spec(), create_value_args(PyBytes::new_bound(py, self.step.value_substrate()).into_any()), None)
+        let value_octets = self.step.string_value_octets()?;
+
+        if self.step.context().native_values() {
+            let value = decode_character_string(self.type_name, &value_octets).map_err(|e| self.step.create_error(&e))?;
+
+            return Ok(PyString::new(py, &value).into_any());
+        }
+
+        clone_asn1_schema_obj(self.step.asn1_spec(), create_value_args(PyBytes::new(py, &value_octets).into_any())?, None)
+    }
+}
+
+
+pub struct TimeDecoder<'py> {
+    step: DecodeStep<'py>,
+    type_name: &'static str
+}
+
+impl<'py> TimeDecoder<'py> {
+    pub(crate) fn new(step: DecodeStep<'py>, type_name: &'static str) -> Self {
+        Self { step, type_name }
+    }
+
+    fn parse_timestamp(&self, value: &[u8]) -> Result<Timestamp, String> {
+        match self.type_name {
+            "UTCTIME" => time::parse_utc_time(value, self.step.context().utc_time_pivot_year()),
+            _ => time::parse_generalized_time(value)
+        }
+    }
+}
+
+impl<'a, 'py> Decoder<'a, 'py> for TimeDecoder<'py> {
+    fn verify_raw(&self) -> PyResult<()> {
+        self.step.check_string_encoding(self.type_name)?;
+
+        let value_octets = self.step.string_value_octets()?;
+
+        // BER permits times without seconds and with local time differentials, which are reported as deviations
+        match self.parse_timestamp(&value_octets) {
+            // such years can only be decoded with a pivot year other than the default
+            Ok(t) if self.type_name == "UTCTIME" && t.year >= UTC_TIME_END_YEAR => self.step.warn("UTCTime value after 2049"),
+            Ok(_) => Ok(()),
+            Err(e) if time::is_ber_time(&value_octets, self.type_name == "GENERALIZEDTIME") => self.step.canonical_violation(&e),
+            Err(e) => Err(self.step.create_error(&e))
+        }
+    }
+
+    fn decode(&'a self) -> PyResult<Bound<'py, PyAny>> where 'py: 'a {
+        let py = self.step.asn1_spec().py();
+
+        let value_octets = self.step.string_value_octets()?;
+
+        let datetime = match self.parse_timestamp(&value_octets) {
+            Ok(t) => create_datetime(self.step.module(), &t)?,
+            Err(_) => py.None().into_bound(py)
+        };
+
+        if self.step.context().native_values() {
+            return Ok(datetime);
+        }
+
+        let decoded = clone_asn1_schema_obj(self.step.asn1_spec(), create_value_args(PyBytes::new(py, &value_octets).into_any())?, None)?;
+
+        decoded.setattr(intern![py, PARSED_DATETIME_ATTR], datetime)?;
+
+        Ok(decoded)
     }
 }
 
@@ -323,21 +705,22 @@ impl<'py> PrintableStringDecoder<'py> {
 
 impl<'a, 'py> Decoder<'a, 'py> for PrintableStringDecoder<'py> {
     fn verify_raw(self: &Self) -> PyResult<()> {
-        match self.step.tag().format() {
-            tag::FORMAT_SIMPLE => (),
-            _ => return Err(self.step.create_error("Invalid PRINTABLESTRING value format"))
-        };
+        self.step.check_string_encoding("PRINTABLESTRING")?;
 
-        match PrintableStringRef::new(self.step.value_substrate()) {
-            Ok(_) => Ok(()),
-            Err(e) => Err(self.step.create_error(&format!("Error decoding PRINTABLESTRING: {}", e.to_string())))
-        }
+        check_printable_string_value(&self.step.string_value_octets()?).map_err(|e| self.step.create_error(&e))
     }
 
     fn decode(self: &'a Self) -> PyResult<Bound<'py, PyAny>> where 'py: 'a {
         let py = self.step.asn1_spec().py();
 
-        clone_asn1_schema_obj(self.step.asn1_spec(), create_value_args(PyBytes::new_bound(py, self.step.value_substrate()).into_any()), None)
+        let value_octets = self.step.string_value_octets()?;
+
+        if self.step.context().native_values() {
+            // the value has passed the PRINTABLESTRING alphabet check
+            return Ok(PyString::new(py, std::str::from_utf8(&value_octets).unwrap()).into_any());
+        }
+
+        clone_asn1_schema_obj(self.step.asn1_spec(), create_value_args(PyBytes::new(py, &value_octets).into_any())?, None)
     }
 }
 
@@ -345,6 +728,11 @@ impl<'a, 'py> Decoder<'a, 'py> for PrintableStringDecoder<'py> {
 fn check_consistency(step: &DecodeStep, asn1_value: &Bound<PyAny>) -> PyResult<()> {
     let py = asn1_value.py();
 
+    // plain Python objects carry no constraints
+    if step.context().native_values() {
+        return Ok(());
+    }
+
     match asn1_value.getattr(intern![py, "isInconsistent"]) {
         Ok(o) => {
             if o.is_truthy()? {
@@ -360,14 +748,14 @@ fn check_consistency(step: &DecodeStep, asn1_value: &Bound<PyAny>) -> PyResult<(
 }
 
 
-fn get_constructed_set_component_kwargs<'py>(m: &'py NativeHelperModule) -> Bound<'py, PyDict> {
+fn get_constructed_set_component_kwargs<'py>(m: &NativeHelperModule<'py>) -> Bound<'py, PyDict> {
     let kwargs = m.module.getattr(intern![m.module.py(), CONSTRUCTED_SET_COMPONENT_KWARGS]).unwrap();
 
     kwargs.downcast_exact().unwrap().clone()
 }
 
 
-fn get_choice_set_component_kwargs<'py>(m: &'py NativeHelperModule) -> Bound<'py, PyDict> {
+fn get_choice_set_component_kwargs<'py>(m: &NativeHelperModule<'py>) -> Bound<'py, PyDict> {
     let kwargs = m.module.getattr(intern![m.module.py(), CHOICE_SET_COMPONENT_KWARGS]).unwrap();
 
     kwargs.downcast_exact().unwrap().clone()
@@ -386,7 +774,7 @@ impl<'py> SequenceDecoder<'py> {
     fn get_named_type_at_index(self: &Self, named_types: &Bound<'py, PyAny>, index: usize) -> PyResult<Bound<'py, PyAny>> {
         match named_types.get_item(index) {
             Ok(n) => Ok(n),
-            Err(_) => return Err(self.step.create_error("Excessive components detected"))
+            Err(_) => Err(self.step.create_error("Excessive components detected"))
         }
     }
 
@@ -401,16 +789,45 @@ impl<'py> SequenceDecoder<'py> {
         }
     }
 
-    fn check_decoded_for_default_value(self: &Self, named_type: &Bound<PyAny>, decoded: &Bound<'py, PyAny>) -> Option<PyErr> {
+    /// Determines the position of an optional or defaulted component from the tags of its TLV so that the component
+    /// path is known before the component is decoded.
+    fn find_component_index(&self, named_types: &Bound<'py, PyAny>, tag_map: &Bound<'py, PyAny>, index: usize, tlv: &[u8]) -> PyResult<Option<usize>> {
+        let py = named_types.py();
+
+        match decoder::find_tag_set_in_tag_map(self.step.module(), tag_map, tlv)? {
+            None => Ok(None),
+            Some(tag_set) => {
+                match named_types.call_method1(intern![py, "getPositionNearType"], (tag_set, index)) {
+                    Ok(i) => Ok(Some(i.extract()?)),
+                    Err(_) => Ok(None)
+                }
+            }
+        }
+    }
+
+    fn default_value(&self, named_type: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyAny>> {
+        let default_value = named_type.getattr(intern![named_type.py(), "asn1Object"])?;
+
+        if self.step.context().native_values() {
+            to_native_value(self.step.module(), &default_value)
+        }
+        else {
+            Ok(default_value)
+        }
+    }
+
+    fn check_decoded_for_default_value(self: &Self, named_type: &Bound<'py, PyAny>, decoded: &Bound<'py, PyAny>) -> PyResult<()> {
         let py = named_type.py();
 
         if named_type.getattr(intern![py, "isDefaulted"]).unwrap().is_truthy().unwrap() {
-            if decoded.eq(named_type.getattr(intern![py, "asn1Object"]).unwrap()).unwrap() {
-                return Some(self.step.create_error("Explicitly encoded default value"))
+            let default_value = self.default_value(named_type)?;
+
+            if decoded.eq(default_value).unwrap() {
+                return self.step.canonical_violation("Explicitly encoded default value")
             }
         }
 
-        None
+        Ok(())
     }
 }
 
@@ -419,57 +836,99 @@ impl<'a, 'py> Decoder<'a, 'py> for SequenceDecoder<'py> {
     fn verify_raw(self: &Self) -> PyResult<()> {
         match self.step.tag().format() {
             tag::FORMAT_CONSTRUCTED => Ok(()),
-            _ => return Err(self.step.create_error("Invalid SEQUENCE value format"))
+            _ => Err(self.step.create_error("Invalid SEQUENCE value format"))
         }
     }
 
     fn decode(self: &'a Self) -> PyResult<Bound<'py, PyAny>> {
         let py = self.step.asn1_spec().py();
 
-        let asn1_object = self.step.asn1_spec().call_method(intern![py, "clone"], PyTuple::empty_bound(py), None)?;
-        asn1_object.call_method(intern![py, "clear"], PyTuple::empty_bound(py), None)?;
+        let native_values = self.step.context().native_values();
+
+        let asn1_object = if native_values {
+            PyDict::new(py).into_any()
+        }
+        else {
+            let asn1_object = self.step.asn1_spec().call_method(intern![py, "clone"], PyTuple::empty(py), None)?;
+            asn1_object.call_method(intern![py, "clear"], PyTuple::empty(py), None)?;
+
+            asn1_object
+        };
 
         let named_types = self.step.asn1_spec().getattr(intern![py, "componentType"])?;
 
+        // components that are written to the JER encoding are not kept in the plain Python dict
+        let writes_jer = self.step.context().writes_jer();
+
+        self.step.context().write_jer_char('{');
+
         let mut index = 0;
         let mut relative_offset = 0;
-        let seen_indices = PySet::empty_bound(py)?;
+        let seen_indices = PySet::empty(py)?;
 
         while relative_offset < self.step.value_substrate_len() {
             let offset_from_parent_tlv = self.step.offset() + self.step.header_len() + relative_offset;
 
-            let named_type = match self.get_named_type_at_index(&named_types, index) {
-                Ok(n) => n,
-                Err(e) => return Err(e)
-            };
+            let named_type = self.get_named_type_at_index(&named_types, index)?;
 
             let is_optional_or_defaulted = named_type.getattr(intern![py, "isOptional"])?.is_truthy()? || named_type.getattr(intern![py, "isDefaulted"])?.is_truthy()?;
 
-            let component_type = match self.get_component_type_for_index(named_types.clone(), &named_type, is_optional_or_defaulted, index) {
-                Ok(c) => c,
-                Err(e) => return Err(e)
-            };
+            let component_type = self.get_component_type_for_index(named_types.clone(), &named_type, is_optional_or_defaulted, index)?;
 
-            let (_, tlv) = match decoder::read_tlv(&self.step.value_substrate()[relative_offset..], offset_from_parent_tlv) {
-                Ok(header_and_tlv) => header_and_tlv,
-                Err(e) => return Err(e)
-            };
+            let (_, tlv) = self.step.context().read_tlv(&self.step.value_substrate()[relative_offset..], offset_from_parent_tlv)?;
 
-            let decoded = match decode_der_rec(self.step.module().clone(), tlv, component_type, None, offset_from_parent_tlv) {
-                Ok(d) => d,
-                Err(e) => return Err(e)
+            // the position of an optional or defaulted component is determined before it is decoded only if it is
+            // needed for the component path or for the key of the component in the plain Python dict
+            let resolved_index = if !is_optional_or_defaulted {
+                Some(index)
+            }
+            else if native_values || self.step.context().tracks_paths() {
+                self.find_component_index(&named_types, &component_type, index, tlv)?
+            }
+            else {
+                None
             };
 
-            match self.check_decoded_for_default_value(&named_type, &decoded) {
-                Some(e) => return Err(e),
-                None => ()
+            let resolved_named_type = match resolved_index {
+                Some(i) if i != index => self.get_named_type_at_index(&named_types, i)?,
+                _ => named_type.clone()
             };
 
-            if is_optional_or_defaulted {
-                index = named_types.call_method(intern![py, "getPositionNearType"], (decoded.getattr(intern![py, "effectiveTagSet"])?, index.into_py(py)), None)?.extract()?;
+            let component_path = self.step.child_path(|| Ok(PathSegment::Name(resolved_named_type.getattr(intern![py, "name"])?.downcast_into::<PyString>()?)))?;
+
+            if writes_jer {
+                let name = resolved_named_type.getattr(intern![py, "name"])?.downcast_into::<PyString>()?;
+
+                self.step.context().write_jer(|w| {
+                    w.write_member_name(&name.to_cow()?, relative_offset == 0);
+
+                    Ok(())
+                })?;
             }
 
-            asn1_object.call_method(intern![py, "setComponentByPosition"], (index, decoded), Some(&get_constructed_set_component_kwargs(&self.step.module())))?;
+            let decoded = decode_der_rec(self.step.context().clone(), tlv, component_type, None, offset_from_parent_tlv, component_path)?;
+
+            let component_index = match resolved_index {
+                Some(i) => i,
+                // plain Python objects do not carry their tags
+                None if native_values => return Err(self.step.create_error("Unable to determine component position")),
+                None => named_types.call_method(intern![py, "getPositionNearType"], (decoded.getattr(intern![py, "effectiveTagSet"])?, index), None)?.extract()?
+            };
+
+            let component_named_type = if component_index == index { named_type } else { self.get_named_type_at_index(&named_types, component_index)? };
+
+            self.check_decoded_for_default_value(&component_named_type, &decoded)?;
+
+            index = component_index;
+
+            if native_values {
+                if !writes_jer {
+                    asn1_object.set_item(component_named_type.getattr(intern![py, "name"])?, decoded)?;
+                }
+            }
+            else {
+                asn1_object.call_method(intern![py, "setComponentByPosition"], (index, decoded), Some(&get_constructed_set_component_kwargs(self.step.module())))?;
+            }
 
             PySetMethods::add(&seen_indices, index)?;
 
@@ -479,11 +938,25 @@ impl<'a, 'py> Decoder<'a, 'py> for SequenceDecoder<'py> {
 
         let required_components = named_types.getattr(intern![py, "requiredComponents"])?;
 
-        if required_components.call_method(intern![py, "issubset"], (seen_indices,), None).unwrap().is_truthy()? {
-            Ok(asn1_object)
-        } else {
-            Err(self.step.create_error("Missing required components"))
+        if !required_components.call_method(intern![py, "issubset"], (&seen_indices,), None).unwrap().is_truthy()? {
+            return Err(self.step.create_error("Missing required components"));
+        }
+
+        self.step.context().write_jer_char('}');
+
+        // absent components with default values are present in the pyasn1 object through its schema and are omitted
+        // from the JER encoding
+        if native_values && !writes_jer {
+            for i in 0..named_types.len()? {
+                let named_type = named_types.get_item(i)?;
+
+                if named_type.getattr(intern![py, "isDefaulted"])?.is_truthy()? && !seen_indices.contains(i)? {
+                    asn1_object.set_item(named_type.getattr(intern![py, "name"])?, self.default_value(&named_type)?)?;
+                }
+            }
         }
+
+        Ok(asn1_object)
     }
 
     fn verify_decoded(self: &Self, asn1_value: &Bound<PyAny>) -> PyResult<()> {
@@ -491,6 +964,22 @@ impl<'a, 'py> Decoder<'a, 'py> for SequenceDecoder<'py> {
     }
 }
 
+fn set_sequence_of_component<'py>(step: &DecodeStep<'py>, asn1_object: &Bound<'py, PyAny>, index: usize, decoded: Bound<'py, PyAny>) -> PyResult<()> {
+    let py = asn1_object.py();
+
+    // components that are written to the JER encoding are not kept in the plain Python list
+    if step.context().writes_jer() {
+        Ok(())
+    }
+    else if step.context().native_values() {
+        asn1_object.downcast::<PyList>()?.append(decoded)
+    }
+    else {
+        asn1_object.call_method(intern![py, "setComponentByPosition"], (index, decoded), Some(&get_constructed_set_component_kwargs(step.module()))).map(|_| ())
+    }
+}
+
+
 pub struct SequenceOfDecoder<'py> {
     step: DecodeStep<'py>
 }
@@ -505,40 +994,53 @@ impl<'a, 'py> Decoder<'a, 'py> for SequenceOfDecoder<'py> {
     fn verify_raw(self: &Self) -> PyResult<()> {
         match self.step.tag().format() {
             tag::FORMAT_CONSTRUCTED => Ok(()),
-            _ => return Err(self.step.create_error("Invalid SEQUENCE value format"))
+            _ => Err(self.step.create_error("Invalid SEQUENCE value format"))
         }
     }
 
     fn decode(self: &'a Self) -> PyResult<Bound<'py, PyAny>> {
         let py = self.step.asn1_spec().py();
 
-        let asn1_object = self.step.asn1_spec().call_method(intern![py, "clone"], PyTuple::empty_bound(py), None)?;
-        asn1_object.call_method(intern![py, "clear"], PyTuple::empty_bound(py), None)?;
+        let asn1_object = if self.step.context().native_values() {
+            PyList::empty(py).into_any()
+        }
+        else {
+            let asn1_object = self.step.asn1_spec().call_method(intern![py, "clone"], PyTuple::empty(py), None)?;
+            asn1_object.call_method(intern![py, "clear"], PyTuple::empty(py), None)?;
+
+            asn1_object
+        };
 
         let mut index = 0;
         let mut relative_offset = 0;
 
         let component_type = self.step.asn1_spec().getattr(intern![py, "componentType"])?;
 
+        self.step.context().write_jer_char('[');
+
         while relative_offset < self.step.value_substrate_len() {
             let offset_from_parent_tlv = self.step.offset() + self.step.header_len() + relative_offset;
 
-            let (_, tlv) = match decoder::read_tlv(&self.step.value_substrate()[relative_offset..], offset_from_parent_tlv) {
-                Ok(header_and_tlv) => header_and_tlv,
-                Err(e) => return Err(e)
-            };
+            let (_, tlv) = self.step.context().read_tlv(&self.step.value_substrate()[relative_offset..], offset_from_parent_tlv)?;
 
-            let decoded = match decode_der_rec(self.step.module().clone(), tlv, component_type.clone(), None, offset_from_parent_tlv) {
-                Ok(d) => d,
-                Err(e) => return Err(e)
-            };
+            if index > 0 {
+                self.step.context().write_jer_char(',');
+            }
+
+            let decoded = decode_der_rec(self.step.context().clone(), tlv, component_type.clone(), None, offset_from_parent_tlv, self.step.child_path(|| Ok(PathSegment::Index(index)))?)?;
 
-            asn1_object.call_method(intern![py, "setComponentByPosition"], (index, decoded), Some(&get_constructed_set_component_kwargs(&self.step.module()))).unwrap();
+            set_sequence_of_component(&self.step, &asn1_object, index, decoded)?;
 
             index += 1;
             relative_offset += tlv.len();
         }
 
+        self.step.context().write_jer_char(']');
+
+        if index == 0 && self.step.context().expects_content(self.step.path())? {
+            self.step.warn("Empty SEQUENCE OF value")?;
+        }
+
         Ok(asn1_object)
     }
 
@@ -563,15 +1065,22 @@ impl<'a, 'py> Decoder<'a, 'py> for SetOfDecoder<'py> {
     fn verify_raw(self: &Self) -> PyResult<()> {
         match self.step.tag().format() {
             tag::FORMAT_CONSTRUCTED => Ok(()),
-            _ => return Err(self.step.create_error("Invalid SET value format"))
+            _ => Err(self.step.create_error("Invalid SET value format"))
         }
     }
 
     fn decode(self: &'a Self) -> PyResult<Bound<'py, PyAny>> {
         let py = self.step.asn1_spec().py();
 
-        let asn1_object = self.step.asn1_spec().call_method(intern![py, "clone"], PyTuple::empty_bound(py), None)?;
-        asn1_object.call_method(intern![py, "clear"], PyTuple::empty_bound(py), None)?;
+        let asn1_object = if self.step.context().native_values() {
+            PyList::empty(py).into_any()
+        }
+        else {
+            let asn1_object = self.step.asn1_spec().call_method(intern![py, "clone"], PyTuple::empty(py), None)?;
+            asn1_object.call_method(intern![py, "clear"], PyTuple::empty(py), None)?;
+
+            asn1_object
+        };
 
         let mut index = 0;
         let mut relative_offset = 0;
@@ -579,18 +1088,17 @@ impl<'a, 'py> Decoder<'a, 'py> for SetOfDecoder<'py> {
 
         let component_type = self.step.asn1_spec().getattr(intern![py, "componentType"])?;
 
+        self.step.context().write_jer_char('[');
+
         while relative_offset < self.step.value_substrate_len() {
             let offset_from_parent_tlv = self.step.offset() + self.step.header_len() + relative_offset;
 
-            let (_, tlv) = match decoder::read_tlv(&self.step.value_substrate()[relative_offset..], offset_from_parent_tlv) {
-                Ok(header_and_tlv) => header_and_tlv,
-                Err(e) => return Err(e)
-            };
+            let (_, tlv) = self.step.context().read_tlv(&self.step.value_substrate()[relative_offset..], offset_from_parent_tlv)?;
 
             match last_tlv {
                 Some(l) => {
                     if l > tlv {
-                        return Err(self.step.create_error(&format!("Out of order component at index {}", index)))
+                        self.step.canonical_violation(&format!("Out of order component at index {}", index))?;
                     }
 
                     last_tlv = Some(tlv);
@@ -598,17 +1106,24 @@ impl<'a, 'py> Decoder<'a, 'py> for SetOfDecoder<'py> {
                 None => last_tlv = Some(tlv)
             };
 
-            let decoded = match decode_der_rec(self.step.module().clone(), tlv, component_type.clone(), None, offset_from_parent_tlv) {
-                Ok(d) => d,
-                Err(e) => return Err(e)
-            };
+            if index > 0 {
+                self.step.context().write_jer_char(',');
+            }
 
-            asn1_object.call_method(intern![py, "setComponentByPosition"], (index, decoded), Some(&get_constructed_set_component_kwargs(&self.step.module())))?;
+            let decoded = decode_der_rec(self.step.context().clone(), tlv, component_type.clone(), None, offset_from_parent_tlv, self.step.child_path(|| Ok(PathSegment::Index(index)))?)?;
+
+            set_sequence_of_component(&self.step, &asn1_object, index, decoded)?;
 
             index += 1;
             relative_offset += tlv.len();
         }
 
+        self.step.context().write_jer_char(']');
+
+        if index == 0 && self.step.context().expects_content(self.step.path())? {
+            self.step.warn("Empty SET OF value")?;
+        }
+
         Ok(asn1_object)
     }
 
@@ -637,16 +1152,12 @@ impl<'a, 'py> Decoder<'a, 'py> for AnyDecoder<'py> {
             self.step.tag_set().ne(self.step.asn1_spec().getattr(intern![py, TAGSET_ATTR])?)?
         };
 
-        let substrate = if is_untagged {
-            self.step.substrate()
+        if is_untagged {
+            clone_asn1_schema_obj_with_value_octets(&self.step, self.step.offset(), self.step.substrate())
         }
         else {
-            self.step.value_substrate()
-        };
-
-        let py_bytes = PyBytes::new_bound(py, substrate);
-
-        clone_asn1_schema_obj(self.step.asn1_spec(), create_value_args(py_bytes.into_any()), None)
+            clone_asn1_schema_obj_with_value_octets(&self.step, self.step.offset() + self.step.header_len(), self.step.value_substrate())
+        }
     }
 }
 
@@ -659,6 +1170,25 @@ impl<'py> ChoiceDecoder<'py> {
     pub(crate) fn new(step: DecodeStep<'py>) -> Self {
         Self { step }
     }
+
+    fn get_component_name(&self, named_types: &Bound<'py, PyAny>, tag_set: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyString>> {
+        let py = named_types.py();
+
+        let position = named_types.call_method1(intern![py, "getPositionByType"], (tag_set,))?;
+
+        Ok(named_types.call_method1(intern![py, "getNameByPosition"], (position,))?.downcast_into::<PyString>()?)
+    }
+
+    /// Opens the JER object of the CHOICE value with the name of the chosen component, which is always determined when
+    /// values are decoded into plain Python objects.
+    fn write_jer_component_name(&self, component_name: &Option<Bound<'py, PyString>>) -> PyResult<()> {
+        self.step.context().write_jer(|w| {
+            w.write_char('{');
+            w.write_member_name(&component_name.as_ref().unwrap().to_cow()?, true);
+
+            Ok(())
+        })
+    }
 }
 
 impl<'a, 'py> Decoder<'a, 'py> for ChoiceDecoder<'py> {
@@ -669,28 +1199,100 @@ impl<'a, 'py> Decoder<'a, 'py> for ChoiceDecoder<'py> {
 
         let component_tag_map = asn1_object.getattr(intern![py, "componentTagMap"])?;
 
-        let decoded_result = if asn1_object.getattr(intern![py, TAGSET_ATTR])?.eq(self.step.tag_set())? {
-            decode_der_rec(self.step.module().clone(), self.step.value_substrate(), component_tag_map, None, self.step.header_len() + self.step.offset())
+        let named_types = asn1_object.getattr(intern![py, "componentType"])?;
+
+        let native_values = self.step.context().native_values();
+
+        // the name of the chosen component is only needed for the component path or for the plain Python dict
+        let needs_component_name = native_values || self.step.context().tracks_paths();
+
+        let (decoded_result, component_name) = if asn1_object.getattr(intern![py, TAGSET_ATTR])?.eq(self.step.tag_set())? {
+            let component_name = if !needs_component_name {
+                None
+            }
+            else {
+                match decoder::find_tag_set_in_tag_map(self.step.module(), &component_tag_map, self.step.value_substrate())? {
+                    Some(t) => Some(self.get_component_name(&named_types, &t)?),
+                    // plain Python objects do not carry their tags
+                    None if native_values => return Err(self.step.create_error("Unable to determine chosen component")),
+                    None => None
+                }
+            };
+
+            let component_path = match &component_name {
+                Some(n) => self.step.path().child(PathSegment::Name(n.clone())),
+                None => self.step.path().clone()
+            };
+
+            self.write_jer_component_name(&component_name)?;
+
+            (decode_der_rec(self.step.context().clone(), self.step.value_substrate(), component_tag_map, None, self.step.header_len() + self.step.offset(), component_path), component_name)
         }
         else {
-            let chosen_spec = match get_chosen_spec(&self.step.module(), &component_tag_map, self.step.tag_set()) {
-                Err(e) => return Err(e),
-                Ok(None) => return decode_explicit(self.step.clone()),
-                Ok(Some(c)) => c
+            let chosen_spec = match get_chosen_spec(self.step.module(), &component_tag_map, self.step.tag_set())? {
+                None => return decode_explicit(self.step.clone()),
+                Some(c) => c
             };
 
-            let new_step = DecodeStep::new(self.step.module().clone(), self.step.substrate(), self.step.header(), chosen_spec, self.step.tag_set().clone(), self.step.offset());
+            let component_name = if needs_component_name { Some(self.get_component_name(&named_types, self.step.tag_set())?) } else { None };
+            let component_path = self.step.child_path(|| Ok(PathSegment::Name(component_name.clone().unwrap())))?;
 
-            decoder::decode_asn1_spec_value(new_step)
+            // the alternative of an untagged CHOICE spans the same TLV as the CHOICE itself
+            self.step.context().copy_position(self.step.path(), &component_path)?;
+
+            self.write_jer_component_name(&component_name)?;
+
+            let new_step = DecodeStep::new(self.step.context().clone(), self.step.substrate(), self.step.header(), chosen_spec.clone(), self.step.tag_set().clone(), self.step.offset(), component_path.clone());
+
+            let decoded_result = decoder::decode_asn1_spec_value(new_step);
+
+            // the alternative is not decoded through decode_der_rec, so it is visited here
+            if let Ok(d) = &decoded_result {
+                self.step.context().visit(&component_path, &chosen_spec, d, self.step.offset(), self.step.substrate())?;
+            }
+
+            (decoded_result, component_name)
         };
 
         match decoded_result {
             Err(e) => Err(e),
+            Ok(d) if native_values => {
+                self.step.context().write_jer_char('}');
+
+                Ok([(component_name.unwrap(), d)].into_py_dict(py)?.into_any())
+            },
             Ok(d) => {
                 let effective_tag_set = d.getattr(intern![py, "effectiveTagSet"])?;
 
-                asn1_object.call_method(intern![py, "setComponentByType"], (effective_tag_set, d), Some(&get_choice_set_component_kwargs(&self.step.module())))
+                asn1_object.call_method(intern![py, "setComponentByType"], (effective_tag_set, d), Some(&get_choice_set_component_kwargs(self.step.module())))
             }
         }
     }
 }
+
+
+pub struct CustomDecoder<'py> {
+    step: DecodeStep<'py>,
+    decoder: Bound<'py, PyAny>
+}
+
+impl<'py> CustomDecoder<'py> {
+    pub(crate) fn new(step: DecodeStep<'py>, decoder: Bound<'py, PyAny>) -> Self {
+        Self { step, decoder }
+    }
+}
+
+impl<'a, 'py> Decoder<'a, 'py> for CustomDecoder<'py> {
+    fn decode(&'a self) -> PyResult<Bound<'py, PyAny>> where 'py: 'a {
+        let py = self.step.asn1_spec().py();
+
+        let value = PyBytes::new(py, self.step.value_substrate());
+
+        self.decoder.call1((self.step.asn1_spec(), value, self.step.tag_set(), self.step.offset())).map_err(|e| {
+            let error = self.step.create_error(&e.value(py).to_string());
+            error.set_cause(py, Some(e));
+
+            error
+        })
+    }
+}
@@ -0,0 +1,454 @@
+use std::fs::File;
+use std::io::{self, BufWriter, Read, Write};
+use std::process::ExitCode;
+
+use der::asn1::ObjectIdentifier;
+use der::{Encode, Header};
+use pyasn1_fasder::asn1_type::check_universal_tlv;
+use pyasn1_fasder::decoder::parse_tlv;
+use pyasn1_fasder::tag::{self, Asn1Tag};
+use pyasn1_fasder::validate::find_out_of_order_set_component;
+
+
+const USAGE: &str = "Usage: fasder-dump [--der | --pem | --hex] [FILE]
+
+Prints the TLV structure of DER-encoded data with offsets, header and value lengths, tags and decoded values, and
+flags every DER encoding error that is detected. The input is read from FILE, or from standard input if FILE is not
+specified or is \"-\". The input format is detected automatically unless a format option is specified.
+
+Options:
+  --der    The input is binary DER
+  --pem    The input consists of one or more PEM blocks
+  --hex    The input is hex-encoded DER; whitespace and colons are ignored
+  --help   Prints this message
+
+The exit status is 0 if no encoding errors were found, 1 if encoding errors were found and 2 if the input could not
+be read.";
+
+const HEX_OCTETS_PER_LINE: usize = 16;
+const MAX_INTEGER_DECIMAL_OCTETS: usize = 16;
+/// Limit on the nesting of constructed and encapsulated TLVs, beyond which values are shown as hex
+const MAX_NESTING_DEPTH: usize = 64;
+
+
+#[derive(Clone, Copy, PartialEq)]
+enum InputFormat {
+    Der,
+    Pem,
+    Hex
+}
+
+
+struct Dumper<W: Write> {
+    out: W,
+    violation_count: usize
+}
+
+impl<W: Write> Dumper<W> {
+    fn new(out: W) -> Self {
+        Self { out, violation_count: 0 }
+    }
+
+    fn write_line(&mut self, offset: Option<usize>, lengths: Option<(usize, usize)>, depth: usize, text: &str) -> io::Result<()> {
+        let offset_col = offset.map(|o| o.to_string()).unwrap_or_default();
+        let (header_col, length_col) = match lengths {
+            Some((h, l)) => (h.to_string(), l.to_string()),
+            None => (String::new(), String::new())
+        };
+
+        writeln!(self.out, "{:>6} {:>2} {:>5}: {}{}", offset_col, header_col, length_col, "  ".repeat(depth), text)
+    }
+
+    fn violation(&mut self, depth: usize, description: &str) -> io::Result<()> {
+        self.violation_count += 1;
+
+        self.write_line(None, None, depth, &format!("!! {}", description))
+    }
+
+    fn hex_lines(&mut self, depth: usize, octets: &[u8]) -> io::Result<()> {
+        for chunk in octets.chunks(HEX_OCTETS_PER_LINE) {
+            self.write_line(None, None, depth, &to_hex(chunk))?;
+        }
+
+        Ok(())
+    }
+
+    fn dump_tlvs(&mut self, substrate: &[u8], base_offset: usize, depth: usize) -> io::Result<()> {
+        if depth > MAX_NESTING_DEPTH && !substrate.is_empty() {
+            self.violation(depth, &format!("Exceeded limit of {} nested TLVs near substrate offset {}", MAX_NESTING_DEPTH, base_offset))?;
+
+            return self.hex_lines(depth, substrate);
+        }
+
+        let mut relative_offset = 0;
+
+        while relative_offset < substrate.len() {
+            let offset = base_offset + relative_offset;
+
+            match parse_tlv(&substrate[relative_offset..]) {
+                Err(e) => {
+                    self.violation(depth, &e.describe(offset))?;
+
+                    return self.hex_lines(depth, &substrate[relative_offset..]);
+                },
+                Ok((header, tlv)) => {
+                    self.dump_tlv(header, tlv, offset, depth)?;
+
+                    relative_offset += tlv.len();
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn dump_tlv(&mut self, header: Header, tlv: &[u8], offset: usize, depth: usize) -> io::Result<()> {
+        let tag = Asn1Tag::new(tlv[0]);
+        let header_len = usize::try_from(header.encoded_len().unwrap()).unwrap();
+        let value = &tlv[header_len..];
+        let lengths = Some((header_len, value.len()));
+        let is_universal = tag.class() == tag::CLASS_UNIVERSAL;
+
+        if tag.format() == tag::FORMAT_CONSTRUCTED {
+            self.write_line(Some(offset), lengths, depth, &format!("{} {{", tag_label(tag)))?;
+
+            if is_universal {
+                if let Err(e) = check_universal_tlv(tag, value) {
+                    self.violation(depth + 1, &e)?;
+                }
+
+                if tag.tag_id() == tag::TAG_ID_SET {
+                    self.check_set_order(value, depth + 1)?;
+                }
+            }
+
+            self.dump_tlvs(value, offset + header_len, depth + 1)?;
+
+            return self.write_line(None, None, depth, "}");
+        }
+
+        if !is_universal {
+            return self.dump_octets(Some(offset), lengths, depth, &tag_label(tag), value);
+        }
+
+        let check_result = check_universal_tlv(tag, value);
+        let label = tag_label(tag);
+
+        match tag.tag_id() {
+            3 if check_result.is_ok() => {
+                self.dump_octets(Some(offset), lengths, depth, &format!("{} unused bits {}", label, value[0]), &value[1..])?;
+
+                if value[0] == 0 {
+                    self.dump_encapsulated(&value[1..], offset + header_len + 1, depth)?;
+                }
+            },
+            4 => {
+                self.dump_octets(Some(offset), lengths, depth, &label, value)?;
+                self.dump_encapsulated(value, offset + header_len, depth)?;
+            },
+            _ => {
+                // values are only rendered once they are known to be well-formed
+                match check_result.as_ref().ok().and_then(|_| render_universal_value(tag.tag_id(), value)) {
+                    Some(r) => self.write_line(Some(offset), lengths, depth, format!("{} {}", label, r).trim_end())?,
+                    None => self.dump_octets(Some(offset), lengths, depth, &label, value)?
+                }
+            }
+        };
+
+        if let Err(e) = check_result {
+            self.violation(depth + 1, &e)?;
+        }
+
+        Ok(())
+    }
+
+    fn dump_octets(&mut self, offset: Option<usize>, lengths: Option<(usize, usize)>, depth: usize, label: &str, octets: &[u8]) -> io::Result<()> {
+        if octets.len() <= HEX_OCTETS_PER_LINE {
+            self.write_line(offset, lengths, depth, format!("{} {}", label, to_hex(octets)).trim_end())
+        }
+        else {
+            self.write_line(offset, lengths, depth, label)?;
+
+            self.hex_lines(depth + 1, octets)
+        }
+    }
+
+    fn dump_encapsulated(&mut self, octets: &[u8], base_offset: usize, depth: usize) -> io::Result<()> {
+        if !is_encapsulated_der(octets, depth) {
+            return Ok(());
+        }
+
+        self.write_line(None, None, depth + 1, "encapsulates {")?;
+        self.dump_tlvs(octets, base_offset, depth + 2)?;
+        self.write_line(None, None, depth + 1, "}")
+    }
+
+    fn check_set_order(&mut self, value: &[u8], depth: usize) -> io::Result<()> {
+        match find_out_of_order_set_component(value) {
+            Some(index) => self.violation(depth, &format!("Out of order SET component at index {}", index)),
+            None => Ok(())
+        }
+    }
+}
+
+
+fn tag_label(tag: Asn1Tag) -> String {
+    match tag.class() {
+        tag::CLASS_UNIVERSAL => match tag::universal_type_name(tag.tag_id()) {
+            Some(n) => n.to_string(),
+            None => format!("[UNIVERSAL {}]", tag.tag_id())
+        },
+        tag::CLASS_APPLICATION => format!("[APPLICATION {}]", tag.tag_id()),
+        tag::CLASS_CONTEXT => format!("[{}]", tag.tag_id()),
+        _ => format!("[PRIVATE {}]", tag.tag_id())
+    }
+}
+
+
+fn to_hex(octets: &[u8]) -> String {
+    octets.iter().map(|o| format!("{:02X}", o)).collect::<Vec<_>>().join(" ")
+}
+
+
+fn quote(text: &str) -> String {
+    format!("'{}'", text.escape_debug())
+}
+
+
+/// Renders the value of a primitive universal type, or returns None if the value is to be shown as hex.
+fn render_universal_value(tag_id: u8, value: &[u8]) -> Option<String> {
+    match tag_id {
+        1 => value.first().map(|&b| if b == 0 { "FALSE" } else { "TRUE" }.to_string()),
+        2 | 10 if value.len() <= MAX_INTEGER_DECIMAL_OCTETS => Some(num_bigint::BigInt::from_signed_bytes_be(value).to_string()),
+        5 => Some(String::new()),
+        6 => ObjectIdentifier::from_bytes(value).ok().map(|o| o.to_string()),
+        12 | 18..=27 => Some(quote(&String::from_utf8_lossy(value))),
+        28 if value.len().is_multiple_of(4) => {
+            let text: Option<String> = value.chunks(4).map(|c| char::from_u32(u32::from_be_bytes([c[0], c[1], c[2], c[3]]))).collect();
+
+            text.map(|t| quote(&t))
+        },
+        30 if value.len().is_multiple_of(2) => {
+            let units = value.chunks(2).map(|c| u16::from_be_bytes([c[0], c[1]]));
+
+            char::decode_utf16(units).collect::<Result<String, _>>().ok().map(|t| quote(&t))
+        },
+        _ => None
+    }
+}
+
+
+/// Determines whether the octets of an OCTET STRING or BIT STRING consist of well-formed DER-encoded SEQUENCEs or
+/// SETs that can be dumped at the specified depth.
+fn is_encapsulated_der(octets: &[u8], depth: usize) -> bool {
+    !octets.is_empty() && (octets[0] == 0x30 || octets[0] == 0x31) && is_well_formed(octets, depth + 2)
+}
+
+
+fn is_well_formed(octets: &[u8], depth: usize) -> bool {
+    if depth > MAX_NESTING_DEPTH && !octets.is_empty() {
+        return false;
+    }
+
+    let mut relative_offset = 0;
+
+    while relative_offset < octets.len() {
+        match parse_tlv(&octets[relative_offset..]) {
+            Ok((header, tlv)) => {
+                let header_len = usize::try_from(header.encoded_len().unwrap()).unwrap();
+
+                if Asn1Tag::new(tlv[0]).format() == tag::FORMAT_CONSTRUCTED && !is_well_formed(&tlv[header_len..], depth + 1) {
+                    return false;
+                }
+
+                relative_offset += tlv.len();
+            },
+            Err(_) => return false
+        }
+    }
+
+    true
+}
+
+
+fn detect_format(input: &[u8]) -> InputFormat {
+    if input.windows(10).any(|w| w == b"-----BEGIN") {
+        InputFormat::Pem
+    }
+    else if input.iter().any(|c| c.is_ascii_hexdigit()) && input.iter().all(|c| c.is_ascii_hexdigit() || c.is_ascii_whitespace() || *c == b':') {
+        InputFormat::Hex
+    }
+    else {
+        InputFormat::Der
+    }
+}
+
+
+fn decode_hex(input: &[u8]) -> Result<Vec<u8>, String> {
+    let digits: Vec<u8> = input.iter().copied().filter(|c| !c.is_ascii_whitespace() && *c != b':').collect();
+
+    if !digits.len().is_multiple_of(2) {
+        return Err("Hex input has an odd number of digits".to_string());
+    }
+
+    digits.chunks(2).map(|pair| {
+        let text = std::str::from_utf8(pair).map_err(|_| "Invalid hex digit in input".to_string())?;
+
+        u8::from_str_radix(text, 16).map_err(|_| format!("Invalid hex digits \"{}\" in input", text))
+    }).collect()
+}
+
+
+fn decode_base64(text: &str) -> Result<Vec<u8>, String> {
+    fn sextet(c: u8) -> Option<u32> {
+        match c {
+            b'A'..=b'Z' => Some(u32::from(c - b'A')),
+            b'a'..=b'z' => Some(u32::from(c - b'a') + 26),
+            b'0'..=b'9' => Some(u32::from(c - b'0') + 52),
+            b'+' => Some(62),
+            b'/' => Some(63),
+            _ => None
+        }
+    }
+
+    let chars: Vec<u8> = text.bytes().filter(|c| !c.is_ascii_whitespace()).collect();
+
+    if !chars.len().is_multiple_of(4) {
+        return Err("Base64 text length is not a multiple of 4".to_string());
+    }
+
+    let mut decoded = Vec::with_capacity(chars.len() / 4 * 3);
+
+    for (i, quad) in chars.chunks(4).enumerate() {
+        let is_last = i == chars.len() / 4 - 1;
+        let padding = quad.iter().rev().take_while(|c| **c == b'=').count();
+
+        if padding > 2 || (padding > 0 && !is_last) {
+            return Err("Invalid Base64 padding".to_string());
+        }
+
+        let mut bits = 0u32;
+
+        for c in &quad[..4 - padding] {
+            bits = (bits << 6) | sextet(*c).ok_or_else(|| format!("Invalid Base64 character '{}'", char::from(*c)))?;
+        }
+
+        bits <<= 6 * padding;
+
+        decoded.extend_from_slice(&bits.to_be_bytes()[1..4 - padding]);
+    }
+
+    Ok(decoded)
+}
+
+
+/// Extracts the label and the decoded contents of every PEM block in the input.
+fn decode_pem(input: &[u8]) -> Result<Vec<(String, Vec<u8>)>, String> {
+    let text = std::str::from_utf8(input).map_err(|_| "PEM input is not valid UTF-8".to_string())?;
+
+    let mut blocks = Vec::new();
+    let mut current: Option<(String, String)> = None;
+
+    for line in text.lines().map(str::trim) {
+        if let Some(label) = line.strip_prefix("-----BEGIN ").and_then(|l| l.strip_suffix("-----")) {
+            current = Some((label.to_string(), String::new()));
+        }
+        else if let Some(label) = line.strip_prefix("-----END ").and_then(|l| l.strip_suffix("-----")) {
+            match current.take() {
+                Some((begin_label, body)) if begin_label == label => blocks.push((begin_label, decode_base64(&body)?)),
+                _ => return Err(format!("Unexpected PEM END line for \"{}\"", label))
+            }
+        }
+        else if let Some((_, body)) = current.as_mut() {
+            // skip RFC 1421 encapsulated headers
+            if !line.contains(':') {
+                body.push_str(line);
+            }
+        }
+    }
+
+    if let Some((label, _)) = current {
+        return Err(format!("Missing PEM END line for \"{}\"", label));
+    }
+
+    Ok(blocks)
+}
+
+
+fn run(args: &[String]) -> Result<usize, String> {
+    let mut format = None;
+    let mut path = None;
+
+    for arg in args {
+        match arg.as_str() {
+            "--der" => format = Some(InputFormat::Der),
+            "--pem" => format = Some(InputFormat::Pem),
+            "--hex" => format = Some(InputFormat::Hex),
+            "--help" | "-h" => {
+                println!("{}", USAGE);
+
+                return Ok(0);
+            },
+            a if a.starts_with("--") => return Err(format!("Unknown option \"{}\"\n\n{}", a, USAGE)),
+            a => {
+                if path.replace(a.to_string()).is_some() {
+                    return Err(format!("Only one input file may be specified\n\n{}", USAGE));
+                }
+            }
+        }
+    }
+
+    let mut input = Vec::new();
+
+    match path.as_deref() {
+        None | Some("-") => io::stdin().read_to_end(&mut input),
+        Some(p) => File::open(p).and_then(|mut f| f.read_to_end(&mut input))
+    }.map_err(|e| format!("Error reading input: {}", e))?;
+
+    let blocks = match format.unwrap_or_else(|| detect_format(&input)) {
+        InputFormat::Der => vec![(None, input)],
+        InputFormat::Hex => vec![(None, decode_hex(&input)?)],
+        InputFormat::Pem => decode_pem(&input)?.into_iter().map(|(l, d)| (Some(l), d)).collect()
+    };
+
+    let mut dumper = Dumper::new(BufWriter::new(io::stdout().lock()));
+
+    for (i, (label, substrate)) in blocks.iter().enumerate() {
+        let write_result = (|| {
+            if i > 0 {
+                writeln!(dumper.out)?;
+            }
+
+            if let Some(l) = label {
+                writeln!(dumper.out, "-----BEGIN {}-----", l)?;
+            }
+
+            dumper.dump_tlvs(substrate, 0, 0)
+        })();
+
+        write_result.map_err(|e| format!("Error writing output: {}", e))?;
+    }
+
+    dumper.out.flush().map_err(|e| format!("Error writing output: {}", e))?;
+
+    Ok(dumper.violation_count)
+}
+
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    match run(&args) {
+        Ok(0) => ExitCode::SUCCESS,
+        Ok(violation_count) => {
+            eprintln!("{} DER encoding error(s) found", violation_count);
+
+            ExitCode::from(1)
+        },
+        Err(e) => {
+            eprintln!("{}", e);
+
+            ExitCode::from(2)
+        }
+    }
+}
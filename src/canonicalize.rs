@@ -0,0 +1,206 @@
+use crate::asn1_type::{check_bit_string_trailer, check_bit_string_value, check_boolean_value, check_integer_value, check_universal_tlv};
+use crate::decoder::{join_bit_string_segments, parse_ber_tlv, parse_string_segments, LengthForm};
+use crate::tag::{self, Asn1Tag};
+use crate::validate::Finding;
+
+
+/// Limit on the nesting of constructed TLVs
+const NESTING_LIMIT: usize = 128;
+
+
+/// Universal tag numbers of the types whose values may be encoded as constructed strings in BER
+fn is_string_tag_id(tag_id: u8) -> bool {
+    matches!(tag_id, 3 | 4 | 7 | 12 | 18..=28 | 30)
+}
+
+
+/// Re-encodes a BER substrate in DER without an ASN.1 schema. Lengths are encoded in definite form with the minimum
+/// number of octets, constructed strings with universal tags are joined into primitive encodings, BOOLEAN, INTEGER
+/// and BIT STRING values are normalized and the components of SETs are sorted. Every change is described by a
+/// finding with the offset of the changed TLV in the original substrate. This function does not require the GIL.
+pub fn canonicalize_der(substrate: &[u8]) -> Result<(Vec<u8>, Vec<Finding>), String> {
+    let mut octets = Vec::with_capacity(substrate.len());
+    let mut changes = Vec::new();
+
+    let tlv_len = canonicalize_tlv(substrate, 0, 0, &mut octets, &mut changes)?;
+
+    if substrate.len() != tlv_len {
+        return Err(format!("{} trailing octet(s) after TLV near substrate offset 0", substrate.len() - tlv_len));
+    }
+
+    Ok((octets, changes))
+}
+
+
+/// Writes the canonical encoding of the TLV at the start of the substrate and returns the length of the original TLV.
+/// The depth is the number of constructed TLVs that enclose the TLV.
+fn canonicalize_tlv(substrate: &[u8], offset: usize, depth: usize, octets: &mut Vec<u8>, changes: &mut Vec<Finding>) -> Result<usize, String> {
+    if depth > NESTING_LIMIT {
+        return Err(format!("Exceeded limit of {} nested TLVs near substrate offset {}", NESTING_LIMIT, offset));
+    }
+
+    let (header, tlv) = parse_ber_tlv(substrate).map_err(|e| e.describe(offset))?;
+
+    let tag = Asn1Tag::new(tlv[0]);
+    let value_offset = offset + header.header_len;
+    let value = &tlv[header.header_len..header.header_len + header.value_len];
+
+    match header.length_form {
+        LengthForm::Definite => (),
+        LengthForm::NonMinimal => changes.push(Finding { offset, description: "Non-minimal length encoding".to_string() }),
+        LengthForm::Indefinite => changes.push(Finding { offset, description: "Indefinite length encoding".to_string() })
+    }
+
+    let is_universal_string = tag.class() == tag::CLASS_UNIVERSAL && is_string_tag_id(tag.tag_id());
+
+    let canonical_tag = if is_universal_string {
+        Asn1Tag::new(u8::from(tag) & !tag::FORMAT_MASK)
+    }
+    else {
+        tag
+    };
+
+    let canonical_value = if is_universal_string && tag.format() == tag::FORMAT_CONSTRUCTED {
+        let type_name = tag::universal_type_name(tag.tag_id()).unwrap();
+
+        changes.push(Finding { offset, description: format!("Invalid {} value format", type_name) });
+
+        let is_bit_string = tag.tag_id() == tag::TAG_ID_BIT_STRING;
+
+        let segments = parse_string_segments(value, value_offset, is_bit_string)?;
+        let segment_values = segments.iter().filter(|s| s.tag.format() != tag::FORMAT_CONSTRUCTED).map(|s| s.value).collect::<Vec<_>>();
+
+        if is_bit_string {
+            join_bit_string_segments(&segment_values).map_err(|e| format!("{} near substrate offset {}", e, offset))?
+        }
+        else {
+            segment_values.concat()
+        }
+    }
+    else if tag.format() == tag::FORMAT_CONSTRUCTED {
+        // without a schema, the components of every SET are sorted as those of a SET OF
+        let set_offset = (tag.class() == tag::CLASS_UNIVERSAL && tag.tag_id() == tag::TAG_ID_SET).then_some(offset);
+
+        canonicalize_components(value, value_offset, depth + 1, set_offset, changes)?
+    }
+    else {
+        value.to_vec()
+    };
+
+    let canonical_value = if canonical_tag.class() == tag::CLASS_UNIVERSAL {
+        let (canonical_value, change) = canonicalize_universal_value(canonical_tag, canonical_value)
+            .map_err(|e| format!("{} near substrate offset {}", e, offset))?;
+
+        if let Some(description) = change {
+            changes.push(Finding { offset, description });
+        }
+
+        canonical_value
+    }
+    else {
+        canonical_value
+    };
+
+    octets.push(u8::from(canonical_tag));
+    write_length(octets, canonical_value.len());
+    octets.extend_from_slice(&canonical_value);
+
+    Ok(tlv.len())
+}
+
+
+/// Normalizes the value of a TLV with a universal tag and returns a description of the change, if any. Errors that
+/// cannot be corrected without knowledge of the intended value are returned as such.
+fn canonicalize_universal_value(tag: Asn1Tag, value: Vec<u8>) -> Result<(Vec<u8>, Option<String>), String> {
+    let (canonical_value, change) = match tag.tag_id() {
+        1 => canonicalize_boolean(value)?,
+        2 | 10 => canonicalize_integer(value, tag::universal_type_name(tag.tag_id()).unwrap())?,
+        tag::TAG_ID_BIT_STRING => canonicalize_bit_string(value)?,
+        _ => (value, None)
+    };
+
+    check_universal_tlv(tag, &canonical_value)?;
+
+    Ok((canonical_value, change))
+}
+
+
+/// Writes the canonical encodings of the components of a constructed value. If the offset of a SET TLV is specified,
+/// the components are sorted by their canonical encodings.
+fn canonicalize_components(value: &[u8], value_offset: usize, depth: usize, set_offset: Option<usize>, changes: &mut Vec<Finding>) -> Result<Vec<u8>, String> {
+    let mut components = Vec::new();
+    let mut relative_offset = 0;
+
+    while relative_offset < value.len() {
+        let mut component = Vec::new();
+
+        relative_offset += canonicalize_tlv(&value[relative_offset..], value_offset + relative_offset, depth, &mut component, changes)?;
+
+        components.push(component);
+    }
+
+    if let Some(offset) = set_offset {
+        if let Some(index) = components.windows(2).position(|w| w[0] > w[1]) {
+            changes.push(Finding { offset, description: format!("Out of order component at index {}", index + 1) });
+
+            components.sort();
+        }
+    }
+
+    Ok(components.concat())
+}
+
+
+fn canonicalize_boolean(value: Vec<u8>) -> Result<(Vec<u8>, Option<String>), String> {
+    match check_boolean_value(&value) {
+        Ok(()) => Ok((value, None)),
+        Err(e) if value.len() == 1 => Ok((vec![0xFF], Some(e))),
+        Err(e) => Err(e)
+    }
+}
+
+
+fn canonicalize_integer(value: Vec<u8>, type_name: &str) -> Result<(Vec<u8>, Option<String>), String> {
+    match check_integer_value(&value, type_name) {
+        Ok(()) => Ok((value, None)),
+        Err(e) if value.is_empty() => Err(e),
+        Err(e) => {
+            // remove leading octets that only extend the sign of the following octet
+            let redundant_len = value.windows(2)
+                .take_while(|w| (w[0] == 0 && w[1] & 0x80 == 0) || (w[0] == 0xFF && w[1] & 0x80 != 0))
+                .count();
+
+            Ok((value[redundant_len..].to_vec(), Some(e)))
+        }
+    }
+}
+
+
+fn canonicalize_bit_string(mut value: Vec<u8>) -> Result<(Vec<u8>, Option<String>), String> {
+    check_bit_string_value(&value)?;
+
+    match check_bit_string_trailer(&value) {
+        Ok(()) => Ok((value, None)),
+        Err(e) => {
+            let last_index = value.len() - 1;
+
+            value[last_index] &= !((1u8 << value[0]) - 1);
+
+            Ok((value, Some(e)))
+        }
+    }
+}
+
+
+fn write_length(octets: &mut Vec<u8>, len: usize) {
+    if len < 0x80 {
+        octets.push(len as u8);
+    }
+    else {
+        let len_octets = len.to_be_bytes();
+        let first_significant = len_octets.iter().position(|o| *o != 0).unwrap();
+
+        octets.push(0x80 | (len_octets.len() - first_significant) as u8);
+        octets.extend_from_slice(&len_octets[first_significant..]);
+    }
+}
@@ -1,46 +1,388 @@
-use crate::asn1_type::{AnyDecoder, BitStringDecoder, BooleanDecoder, CharacterStringDecoder, ChoiceDecoder, Decoder, IntegerDecoder, NullDecoder, ObjectIdentifierDecoder, OctetStringDecoder, PrintableStringDecoder, SequenceDecoder, SequenceOfDecoder, SetOfDecoder};
-use crate::tag::Asn1Tag;
-use crate::{NativeHelperModule, Pyasn1FasderError, HELPER_MODULE_ATTR, TYPE_MAP};
+use crate::asn1_type::{AnyDecoder, BitStringDecoder, BooleanDecoder, CharacterStringDecoder, ChoiceDecoder, CustomDecoder, Decoder, IntegerDecoder, IntegerRule, NullDecoder, ObjectIdentifierDecoder, OctetStringDecoder, PrintableStringDecoder, SequenceDecoder, SequenceOfDecoder, SetOfDecoder, TimeDecoder};
+use crate::jer::{JerWriter, OctetsEncoding};
+use crate::path::{ComponentPath, PathSegment};
+use crate::time;
+use crate::tag::{Asn1Tag, CLASS_UNIVERSAL, FORMAT_CONSTRUCTED, TAG_ID_BIT_STRING, TAG_ID_OCTET_STRING};
+use crate::{NativeHelperModule, Pyasn1FasderError, TlvLengthError, HELPER_MODULE_ATTR, NESTED_EXPLICIT_TAG_LIMIT, TYPE_MAP};
 use der::{Encode, Header, Reader};
 use pyo3::prelude::{PyAnyMethods, PyModule, PyTypeMethods};
-use pyo3::types::PyDict;
-use pyo3::types::PyDictMethods;
+use pyo3::types::{PyBytes, PyDict, PyList, PyMemoryView, PySet, PySlice, PyTuple};
+use pyo3::types::{PyDictMethods, PyListMethods, PySetMethods};
 use pyo3::PyResult;
 use pyo3::{Bound, PyAny};
 use pyo3::PyErr;
+use pyo3::intern;
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::rc::Rc;
 
 const TYPE_ID_ATTR: &str = "typeId";
+const WARNING_CLS: &str = "_WARNING_CLS";
+const ISSUE_WARNING: &str = "_issue_warning";
+
+/// Limit on the nesting of indefinite-length encodings and of segments of constructed strings
+const BER_NESTING_LIMIT: usize = 32;
+
+/// Length of the segments of constructed CER string encodings
+const CER_SEGMENT_LEN: usize = 1000;
+
+/// Maximum number of octets that follow the initial length octet in lengths accepted by the DER reader
+const DER_MAX_LENGTH_OCTETS: usize = 4;
+
+
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
+pub enum EncodingRules {
+    #[default]
+    Der,
+    /// BER encodings are accepted and every deviation from DER is recorded
+    Ber,
+    /// Constructed values must use indefinite-length encodings and strings longer than 1000 octets must be segmented
+    Cer
+}
+
+
+#[derive(Default)]
+pub struct DecodeOptions<'py> {
+    pub encoding_rules: EncodingRules,
+    /// Memoryview over the original substrate object from which value payloads are sliced
+    pub substrate_view: Option<Bound<'py, PyMemoryView>>,
+    /// Mapping of component paths to positions, populated as components are decoded
+    pub positions: Option<Bound<'py, PyDict>>,
+    /// List of deviations from DER, populated when decoding BER
+    pub deviations: Option<Bound<'py, PyList>>,
+    /// Values are decoded into plain Python objects rather than pyasn1 objects
+    pub native_values: bool,
+    /// First year of the century that two-digit UTCTime years are mapped onto, if not the default
+    pub utc_time_pivot_year: Option<u16>,
+    /// Paths of the components that are returned as the octets of their TLVs without being decoded
+    pub opaque_paths: Option<Bound<'py, PySet>>,
+    /// pyasn1 types whose values are returned as the octets of their TLVs without being decoded
+    pub opaque_types: Option<Bound<'py, PyTuple>>,
+    /// Mapping of component paths to the rules that their INTEGER values must satisfy
+    pub integer_rules: Option<Bound<'py, PyDict>>,
+    /// Paths of the SEQUENCE OF and SET OF components that are expected to have at least one component
+    pub non_empty_paths: Option<Bound<'py, PySet>>,
+    /// Callable that receives warnings instead of them being issued through the warnings module
+    pub warning_callback: Option<Bound<'py, PyAny>>,
+    /// Callable that is invoked with every decoded component
+    pub visitor: Option<Bound<'py, PyAny>>,
+    /// Representation of OCTET STRING values in the JER encoding that is written while decoding, if it is written.
+    /// Writing JER requires values to be decoded into plain Python objects.
+    pub jer_octets_encoding: Option<OctetsEncoding>
+}
+
+
+pub struct DecodeContext<'py> {
+    module: NativeHelperModule<'py>,
+    options: DecodeOptions<'py>,
+    tracks_paths: bool,
+    jer_writer: Option<RefCell<JerWriter>>
+}
+
+impl<'py> DecodeContext<'py> {
+    pub fn new(module: NativeHelperModule<'py>, options: DecodeOptions<'py>) -> Self {
+        // UTCTime values after 2049, which are reported with their path, can only be decoded with a later pivot year
+        let tracks_paths = options.positions.is_some() || options.visitor.is_some() || options.opaque_paths.is_some() ||
+            options.integer_rules.is_some() || options.non_empty_paths.is_some() ||
+            options.utc_time_pivot_year.is_some_and(|y| y > time::DEFAULT_UTC_TIME_PIVOT_YEAR);
+
+        let jer_writer = options.jer_octets_encoding.map(|e| RefCell::new(JerWriter::new(e)));
+
+        Self { module, options, tracks_paths, jer_writer }
+    }
+
+    pub fn module(&self) -> &NativeHelperModule<'py> {
+        &self.module
+    }
+
+    pub fn encoding_rules(&self) -> EncodingRules {
+        self.options.encoding_rules
+    }
+
+    pub fn native_values(&self) -> bool {
+        self.options.native_values
+    }
+
+    /// Returns whether the paths of components are tracked. Paths are only built if an option that reports or selects
+    /// components by path is specified; otherwise, the path of every component is the root path.
+    pub fn tracks_paths(&self) -> bool {
+        self.tracks_paths
+    }
+
+    /// Returns whether the JER encoding of the decoded value is written while decoding.
+    pub fn writes_jer(&self) -> bool {
+        self.jer_writer.is_some()
+    }
+
+    /// Writes to the JER encoding of the decoded value, if it is written.
+    pub fn write_jer<F: FnOnce(&mut JerWriter) -> PyResult<()>>(&self, f: F) -> PyResult<()> {
+        match &self.jer_writer {
+            Some(w) => f(&mut w.borrow_mut()),
+            None => Ok(())
+        }
+    }
+
+    /// Writes a JSON structural character to the JER encoding of the decoded value, if it is written.
+    pub fn write_jer_char(&self, c: char) {
+        if let Some(w) = &self.jer_writer {
+            w.borrow_mut().write_char(c);
+        }
+    }
+
+    /// Returns the JER encoding that has been written, if it is written.
+    pub fn take_jer(&self) -> Option<String> {
+        self.jer_writer.as_ref().map(|w| w.borrow_mut().take_json())
+    }
+
+    pub fn utc_time_pivot_year(&self) -> u16 {
+        self.options.utc_time_pivot_year.unwrap_or(time::DEFAULT_UTC_TIME_PIVOT_YEAR)
+    }
+
+    /// Returns whether the component at the specified path with the specified ASN.1 specification is returned as the
+    /// octets of its TLV without being decoded.
+    pub fn is_opaque(&self, path: &ComponentPath<'py>, asn1_spec: &Bound<'py, PyAny>, tlv: &[u8]) -> PyResult<bool> {
+        if let Some(opaque_types) = &self.options.opaque_types {
+            // the spec of an optional or defaulted component is a tag map, from which the type of the TLV is chosen
+            let chosen_spec = if asn1_spec.get_type().is(&self.module.tagmap_cls) {
+                match find_tag_set_in_tag_map(&self.module, asn1_spec, tlv)? {
+                    Some(t) => asn1_spec.get_item(t)?,
+                    None => asn1_spec.clone()
+                }
+            }
+            else {
+                asn1_spec.clone()
+            };
+
+            if chosen_spec.is_instance(opaque_types)? {
+                return Ok(true);
+            }
+        }
+
+        match &self.options.opaque_paths {
+            Some(opaque_paths) => opaque_paths.contains(path.to_py_tuple(asn1_spec.py())?),
+            None => Ok(false)
+        }
+    }
+
+    /// Returns the rule that the INTEGER value of the component at the specified path must satisfy, if any.
+    pub fn integer_rule(&self, path: &ComponentPath<'py>) -> PyResult<Option<IntegerRule>> {
+        match &self.options.integer_rules {
+            Some(integer_rules) => integer_rules.get_item(path.to_py_tuple(integer_rules.py())?)?.map(|r| r.extract()).transpose(),
+            None => Ok(None)
+        }
+    }
+
+    /// Returns whether the SEQUENCE OF or SET OF component at the specified path is expected to have at least one
+    /// component.
+    pub fn expects_content(&self, path: &ComponentPath<'py>) -> PyResult<bool> {
+        match &self.options.non_empty_paths {
+            Some(non_empty_paths) => non_empty_paths.contains(path.to_py_tuple(non_empty_paths.py())?),
+            None => Ok(false)
+        }
+    }
+
+    /// Returns a memoryview over the specified range of the original substrate object, or None if value payloads are
+    /// to be copied.
+    pub fn substrate_slice(&self, start: usize, end: usize) -> PyResult<Option<Bound<'py, PyAny>>> {
+        match &self.options.substrate_view {
+            None => Ok(None),
+            Some(view) => {
+                let slice = PySlice::new(view.py(), isize::try_from(start)?, isize::try_from(end)?, 1);
+
+                Ok(Some(view.get_item(slice)?))
+            }
+        }
+    }
+
+    /// Reads the header and the TLV octets of the TLV at the start of the substrate according to the encoding rules
+    /// in use.
+    pub fn read_tlv<'s>(&self, substrate: &'s [u8], offset: usize) -> PyResult<(TlvHeader, &'s [u8])> {
+        match self.options.encoding_rules {
+            EncodingRules::Der => read_tlv(substrate, offset).map(|(h, tlv)| (TlvHeader::from(h), tlv)),
+            EncodingRules::Ber | EncodingRules::Cer => parse_ber_tlv(substrate).map_err(|e| e.into_py_err(offset))
+        }
+    }
+
+    fn record_deviation(&self, offset: usize, description: &str) -> PyResult<()> {
+        match &self.options.deviations {
+            Some(deviations) => deviations.append(self.module.finding_cls.call1((offset, description))?),
+            None => Ok(())
+        }
+    }
+
+    /// Emits a non-fatal diagnostic for a value that is suspicious but does not violate the encoding rules. Warnings are
+    /// passed to the warning callback if one was specified; otherwise, they are issued through the warnings module.
+    pub fn warn(&self, offset: usize, path: &ComponentPath<'py>, description: &str) -> PyResult<()> {
+        let py = self.module.module.py();
+
+        let warning = self.module.module.getattr(intern![py, WARNING_CLS])?.call1((description, offset, path.to_py_tuple(py)?))?;
+
+        match &self.options.warning_callback {
+            Some(callback) => callback.call1((warning,))?,
+            None => self.module.module.getattr(intern![py, ISSUE_WARNING])?.call1((warning,))?
+        };
+
+        Ok(())
+    }
+
+    pub fn has_visitor(&self) -> bool {
+        self.options.visitor.is_some()
+    }
+
+    /// Invokes the visitor with the path, the ASN.1 specification, the decoded value, the substrate offset and the TLV
+    /// octets of a decoded component. An exception raised by the visitor aborts decoding and is wrapped in an error
+    /// that identifies the component.
+    pub fn visit(&self, path: &ComponentPath<'py>, asn1_spec: &Bound<'py, PyAny>, value: &Bound<'py, PyAny>, offset: usize, tlv: &[u8]) -> PyResult<()> {
+        let visitor = match &self.options.visitor {
+            Some(v) => v,
+            None => return Ok(())
+        };
+
+        let py = visitor.py();
+
+        if let Err(e) = visitor.call1((path.to_py_tuple(py)?, asn1_spec, value, offset, PyBytes::new(py, tlv))) {
+            let asn1_spec_type = asn1_spec.get_type();
+
+            let error = Pyasn1FasderError::new_err(format!("Error visiting \"{}\" TLV near substrate offset {}: {}", asn1_spec_type.name()?, offset, e.value(py)));
+            error.set_cause(py, Some(e));
+
+            return Err(error);
+        }
+
+        Ok(())
+    }
+
+    /// Handles the violation of a rule that DER imposes on top of BER. When decoding BER, the violation is recorded
+    /// as a deviation and decoding continues; otherwise, the specified error is raised. The rules that are checked
+    /// through this method are also imposed by CER.
+    pub fn canonical_violation(&self, offset: usize, description: &str, error: impl FnOnce() -> PyErr) -> PyResult<()> {
+        match self.options.encoding_rules {
+            EncodingRules::Ber => self.record_deviation(offset, description),
+            EncodingRules::Der | EncodingRules::Cer => Err(error())
+        }
+    }
+
+    /// Checks the length octets of the specified header against the encoding rules in use. When decoding BER, any
+    /// deviation from DER is recorded.
+    pub fn check_length_form(&self, tag: Asn1Tag, header: TlvHeader, offset: usize) -> PyResult<()> {
+        match self.options.encoding_rules {
+            // the DER reader only accepts definite lengths in the minimum number of octets
+            EncodingRules::Der => Ok(()),
+            EncodingRules::Ber => {
+                match header.length_form {
+                    LengthForm::Definite => Ok(()),
+                    LengthForm::NonMinimal => self.record_deviation(offset, "Non-minimal length encoding"),
+                    LengthForm::Indefinite => self.record_deviation(offset, "Indefinite length encoding")
+                }
+            },
+            EncodingRules::Cer => {
+                if tag.format() == FORMAT_CONSTRUCTED && header.length_form != LengthForm::Indefinite {
+                    Err(Pyasn1FasderError::new_err(format!("Definite length in constructed CER encoding near substrate offset {}", offset)))
+                }
+                else if header.length_form == LengthForm::NonMinimal {
+                    Err(Pyasn1FasderError::new_err(format!("Non-minimal length in primitive CER encoding near substrate offset {}", offset)))
+                }
+                else {
+                    Ok(())
+                }
+            }
+        }
+    }
+
+    /// Checks that the value of a constructed TLV consists of complete TLVs at every level of nesting without decoding
+    /// them. The values are walked with an explicit stack, as the nesting depth of opaque values is not bounded.
+    pub fn check_nested_framing(&self, value: &[u8], offset: usize) -> PyResult<()> {
+        let mut pending_values = vec![(value, offset)];
+
+        while let Some((value, value_offset)) = pending_values.pop() {
+            let mut relative_offset = 0;
+
+            while relative_offset < value.len() {
+                let tlv_offset = value_offset + relative_offset;
+
+                let (header, tlv) = self.read_tlv(&value[relative_offset..], tlv_offset)?;
+
+                let tag = Asn1Tag::new(tlv[0]);
+
+                self.check_length_form(tag, header, tlv_offset)?;
+
+                if tag.format() == FORMAT_CONSTRUCTED {
+                    pending_values.push((&tlv[header.header_len..header.header_len + header.value_len], tlv_offset + header.header_len));
+                }
+
+                relative_offset += tlv.len();
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn record_position(&self, path: &ComponentPath<'py>, offset: usize, header: TlvHeader) -> PyResult<()> {
+        match &self.options.positions {
+            None => Ok(()),
+            Some(positions) => {
+                let py = positions.py();
+
+                // the value of an indefinite-length encoding includes the end-of-contents octets
+                let value_len = header.tlv_len() - header.header_len;
+
+                let position = self.module.position_cls.call1((offset, header.header_len, value_len))?;
+
+                positions.set_item(path.to_py_tuple(py)?, position)
+            }
+        }
+    }
+
+    pub fn copy_position(&self, from_path: &ComponentPath<'py>, to_path: &ComponentPath<'py>) -> PyResult<()> {
+        match &self.options.positions {
+            None => Ok(()),
+            Some(positions) => {
+                let py = positions.py();
+
+                match PyDictMethods::get_item(positions, from_path.to_py_tuple(py)?)? {
+                    None => Ok(()),
+                    Some(position) => positions.set_item(to_path.to_py_tuple(py)?, position)
+                }
+            }
+        }
+    }
+}
 
 
 #[derive(Clone)]
 pub struct DecodeStep<'py> {
-    module: NativeHelperModule<'py>,
+    context: Rc<DecodeContext<'py>>,
     substrate: &'py [u8],
-    header: Header,
+    header: TlvHeader,
     asn1_spec: Bound<'py, PyAny>,
     tag_set: Bound<'py, PyAny>,
-    offset: usize
+    offset: usize,
+    path: ComponentPath<'py>
 }
 
 impl<'py> DecodeStep<'py> {
-    pub fn new(module: NativeHelperModule<'py>, substrate: &'py [u8], header: Header, asn1_spec: Bound<'py, PyAny>, tag_set: Bound<'py, PyAny>, offset: usize) -> Self {
-        Self { module, substrate, header, asn1_spec, tag_set, offset }
+    pub fn new(context: Rc<DecodeContext<'py>>, substrate: &'py [u8], header: TlvHeader, asn1_spec: Bound<'py, PyAny>, tag_set: Bound<'py, PyAny>, offset: usize, path: ComponentPath<'py>) -> Self {
+        Self { context, substrate, header, asn1_spec, tag_set, offset, path }
+    }
+
+    pub fn context(&self) -> &Rc<DecodeContext<'py>> {
+        &self.context
     }
 
     pub fn module(&self) -> &NativeHelperModule<'py> {
-        &self.module
+        self.context.module()
     }
 
     pub fn substrate(&self) -> &'py [u8] {
         self.substrate
     }
 
-    pub fn header(&self) -> Header {
+    pub fn header(&self) -> TlvHeader {
         self.header
     }
 
     pub fn header_len(&self) -> usize {
-        usize::try_from(self.header.encoded_len().unwrap()).unwrap()
+        self.header.header_len
     }
 
     pub fn tag(&self) -> Asn1Tag {
@@ -56,17 +398,131 @@ impl<'py> DecodeStep<'py> {
     }
 
     pub fn value_substrate_len(&self) -> usize {
-        usize::try_from(self.header.length).unwrap()
+        self.header.value_len
     }
 
     pub fn value_substrate(&self) -> &'py [u8] {
-        &self.substrate[self.header_len()..]
+        &self.substrate[self.header_len()..self.header_len() + self.value_substrate_len()]
+    }
+
+    /// Checks that the value of a string type is encoded in the primitive or constructed form as required by the
+    /// encoding rules in use.
+    pub fn check_string_encoding(&self, type_name: &str) -> PyResult<()> {
+        let is_constructed = self.tag().format() == FORMAT_CONSTRUCTED;
+
+        match self.context.encoding_rules() {
+            EncodingRules::Cer if is_constructed => {
+                let segments = self.string_segments()?;
+
+                if segments.iter().map(|s| s.len()).sum::<usize>() <= CER_SEGMENT_LEN {
+                    return Err(self.create_error(&format!("Constructed encoding of {} value of {} octets or fewer", type_name, CER_SEGMENT_LEN)));
+                }
+
+                let last_index = segments.len() - 1;
+
+                // every segment except the last must be exactly as long as the maximum segment length
+                for (i, segment) in segments.iter().enumerate() {
+                    let is_valid_len = if i < last_index {
+                        segment.len() == CER_SEGMENT_LEN
+                    }
+                    else {
+                        !segment.is_empty() && segment.len() <= CER_SEGMENT_LEN
+                    };
+
+                    if !is_valid_len {
+                        return Err(self.create_error(&format!("Invalid {} segment length at index {}", type_name, i)));
+                    }
+                }
+
+                Ok(())
+            },
+            EncodingRules::Cer => {
+                if self.value_substrate_len() > CER_SEGMENT_LEN {
+                    Err(self.create_error(&format!("Primitive encoding of {} value longer than {} octets", type_name, CER_SEGMENT_LEN)))
+                }
+                else {
+                    Ok(())
+                }
+            },
+            _ if is_constructed => self.canonical_violation(&format!("Invalid {} value format", type_name)),
+            _ => Ok(())
+        }
+    }
+
+    fn is_bit_string(&self) -> bool {
+        self.tag().class() == CLASS_UNIVERSAL && self.tag().tag_id() == TAG_ID_BIT_STRING
+    }
+
+    /// Returns the value octets of the primitive segments of a constructed string encoding in substrate order.
+    fn string_segments(&self) -> PyResult<Vec<&'py [u8]>> {
+        let segments = parse_string_segments(self.value_substrate(), self.offset + self.header_len(), self.is_bit_string())
+            .map_err(|e| self.create_error(&e))?;
+
+        let mut values = Vec::new();
+
+        for segment in segments {
+            self.context.check_length_form(segment.tag, segment.header, segment.offset)?;
+
+            if segment.tag.format() != FORMAT_CONSTRUCTED {
+                values.push(segment.value);
+            }
+            else if self.context.encoding_rules() == EncodingRules::Cer {
+                return Err(self.create_error(&format!("Nested string segment in CER encoding near substrate offset {}", segment.offset)));
+            }
+        }
+
+        Ok(values)
+    }
+
+    /// Returns the value octets of a string type. The segments of a constructed encoding, which is only accepted when
+    /// decoding BER or CER, are concatenated. For BIT STRINGs, the returned octets start with the trailer length of
+    /// the last segment.
+    pub fn string_value_octets(&self) -> PyResult<Cow<'py, [u8]>> {
+        if self.tag().format() != FORMAT_CONSTRUCTED {
+            return Ok(Cow::Borrowed(self.value_substrate()));
+        }
+
+        let segments = self.string_segments()?;
+
+        if self.is_bit_string() {
+            join_bit_string_segments(&segments).map(Cow::Owned).map_err(|e| self.create_error(&e))
+        }
+        else {
+            Ok(Cow::Owned(segments.concat()))
+        }
     }
 
     pub fn offset(&self) -> usize {
         self.offset
     }
 
+    pub fn path(&self) -> &ComponentPath<'py> {
+        &self.path
+    }
+
+    /// Returns the path of a component of the value of this step with the segment created by the specified function,
+    /// or the root path if component paths are not tracked.
+    pub fn child_path<F>(&self, segment: F) -> PyResult<ComponentPath<'py>>
+        where F: FnOnce() -> PyResult<PathSegment<'py>>
+    {
+        if self.context.tracks_paths() {
+            Ok(self.path.child(segment()?))
+        }
+        else {
+            Ok(ComponentPath::root())
+        }
+    }
+
+    /// Handles the violation of a rule that DER and CER impose on top of BER by the TLV of this step.
+    pub fn canonical_violation(&self, description: &str) -> PyResult<()> {
+        self.context.canonical_violation(self.offset, description, || self.create_error(description))
+    }
+
+    /// Emits a warning for the TLV of this step.
+    pub fn warn(&self, description: &str) -> PyResult<()> {
+        self.context.warn(self.offset, &self.path, description)
+    }
+
     pub fn create_error(&self, description: &str) -> PyErr {
         let asn1_spec_type = self.asn1_spec.get_type();
         let asn1_spec_name = asn1_spec_type.name().unwrap();
@@ -76,53 +532,435 @@ impl<'py> DecodeStep<'py> {
 }
 
 
-pub fn read_tlv(substrate: &[u8], offset: usize) -> PyResult<(Header, &[u8])> {
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LengthForm {
+    Definite,
+    /// Definite length encoded in more octets than necessary
+    NonMinimal,
+    /// Indefinite length, where the value is followed by end-of-contents octets
+    Indefinite
+}
+
+
+/// The header of a TLV, which may use the length encodings permitted by BER.
+#[derive(Clone, Copy, Debug)]
+pub struct TlvHeader {
+    pub header_len: usize,
+    /// Length of the value octets, excluding any end-of-contents octets
+    pub value_len: usize,
+    pub length_form: LengthForm
+}
+
+impl TlvHeader {
+    pub fn tlv_len(&self) -> usize {
+        match self.length_form {
+            LengthForm::Indefinite => self.header_len + self.value_len + 2,
+            _ => self.header_len + self.value_len
+        }
+    }
+}
+
+impl From<Header> for TlvHeader {
+    fn from(header: Header) -> Self {
+        Self {
+            header_len: usize::try_from(header.encoded_len().unwrap()).unwrap(),
+            value_len: usize::try_from(header.length).unwrap(),
+            length_form: LengthForm::Definite
+        }
+    }
+}
+
+
+/// The kinds of errors in the length octets of a TLV header. The names of the kinds are the values of the 'kind'
+/// attribute of 'TlvLengthError'.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LengthErrorKind {
+    /// The substrate ends before the length octets do
+    Truncated,
+    /// The length is encoded in indefinite form, which DER does not permit
+    Indefinite,
+    /// The length is encoded in long form with more octets than necessary, which DER does not permit
+    NonMinimal,
+    /// The length is encoded with the reserved initial octet or with more octets than are supported
+    Oversized,
+    /// The length exceeds the number of octets that follow the header
+    ExceedsInput
+}
+
+impl LengthErrorKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            LengthErrorKind::Truncated => "truncated",
+            LengthErrorKind::Indefinite => "indefinite",
+            LengthErrorKind::NonMinimal => "non-minimal",
+            LengthErrorKind::Oversized => "oversized",
+            LengthErrorKind::ExceedsInput => "exceeds-input"
+        }
+    }
+
+    fn description(&self) -> &'static str {
+        match self {
+            LengthErrorKind::Truncated => "Substrate under-run in TLV header",
+            LengthErrorKind::Indefinite => "Indefinite length encoding",
+            LengthErrorKind::NonMinimal => "Non-minimal length encoding",
+            LengthErrorKind::Oversized => "Unsupported number of length octets",
+            LengthErrorKind::ExceedsInput => "Length exceeds remaining substrate"
+        }
+    }
+}
+
+
+/// An error in the length octets of a TLV header.
+#[derive(Debug)]
+pub struct LengthError {
+    pub kind: LengthErrorKind,
+    /// The length octets that are present in the substrate, including the initial length octet
+    pub length_octets: Vec<u8>,
+    /// The length declared by the length octets, or None if it cannot be determined
+    pub declared_length: Option<usize>,
+    /// The number of octets that follow the length octets
+    pub available_length: usize
+}
+
+impl LengthError {
+    fn new(kind: LengthErrorKind, length_octets: &[u8], declared_length: Option<usize>, available_length: usize) -> Self {
+        Self { kind, length_octets: length_octets.to_vec(), declared_length, available_length }
+    }
+
+    fn describe(&self) -> String {
+        let length_octets = self.length_octets.iter().map(|o| format!("{:02X}", o)).collect::<Vec<_>>().join(" ");
+
+        match self.declared_length {
+            Some(l) => format!("{} (length octets: {}, declared length: {}, available octets: {})", self.kind.description(), length_octets, l, self.available_length),
+            None => format!("{} (length octets: {}, available octets: {})", self.kind.description(), length_octets, self.available_length)
+        }
+    }
+}
+
+
+#[derive(Debug)]
+pub enum TlvError {
+    Header(der::Error),
+    Value(der::Error),
+    Length(LengthError),
+    Ber(&'static str)
+}
+
+impl TlvError {
+    pub fn describe(&self, offset: usize) -> String {
+        match self {
+            TlvError::Header(e) => format!("Error reading TLV header near substrate offset {}: {}", offset, e),
+            TlvError::Value(e) => format!("Error reading TLV near substrate offset {}: {}", offset, e),
+            TlvError::Length(e) => format!("Error reading TLV header near substrate offset {}: {}", offset, e.describe()),
+            TlvError::Ber(e) => format!("Error reading TLV near substrate offset {}: {}", offset, e)
+        }
+    }
+
+    /// Converts the error into a Python exception. Errors in length octets are raised as 'TlvLengthError', which
+    /// carries the details of the error as attributes.
+    pub fn into_py_err(self, offset: usize) -> PyErr {
+        let message = self.describe(offset);
+
+        match self {
+            TlvError::Length(e) => TlvLengthError::new_err((message, e.kind.name(), offset, e.declared_length, e.available_length, e.length_octets)),
+            _ => Pyasn1FasderError::new_err(message)
+        }
+    }
+}
+
+
+/// Reads the header and the TLV octets of the TLV at the start of the substrate. This function does not require the
+/// GIL.
+pub fn parse_tlv(substrate: &[u8]) -> Result<(Header, &[u8]), TlvError> {
     let mut reader = der::SliceReader::new(substrate).unwrap();
 
-    let header = match reader.peek_header() {
-        Err(e) => return Err(Pyasn1FasderError::new_err(format!("Error reading TLV header near substrate offset {}: {}", offset, e.to_string()))),
-        Ok(h) => h
+    // errors in the length octets are classified, as the errors of the DER reader do not identify them
+    let header = reader.peek_header().map_err(|e| find_der_length_error(substrate).map_or(TlvError::Header(e), TlvError::Length))?;
+
+    let tlv_octets = reader.tlv_bytes().map_err(|e| find_der_length_error(substrate).map_or(TlvError::Value(e), TlvError::Length))?;
+
+    Ok((header, tlv_octets))
+}
+
+
+pub fn read_tlv(substrate: &[u8], offset: usize) -> PyResult<(Header, &[u8])> {
+    parse_tlv(substrate).map_err(|e| e.into_py_err(offset))
+}
+
+
+/// Returns the error in the length octets of the TLV at the start of the substrate that violate DER. Returns None if
+/// the length octets are valid DER.
+fn find_der_length_error(substrate: &[u8]) -> Option<LengthError> {
+    let (header_len, value_len, length_form) = match parse_ber_header(substrate) {
+        Ok(h) => h,
+        Err(TlvError::Length(e)) => return Some(e),
+        Err(_) => return None
     };
 
-    let tlv_octets = match reader.tlv_bytes() {
-        Err(e) => return Err(Pyasn1FasderError::new_err(format!("Error reading TLV near substrate offset {}: {}", offset, e.to_string()))),
-        Ok(tlv) => tlv
+    let length_octets = &substrate[1..header_len];
+    let available_length = substrate.len() - header_len;
+
+    let kind = match value_len {
+        None => LengthErrorKind::Indefinite,
+        Some(_) if length_form == LengthForm::NonMinimal => LengthErrorKind::NonMinimal,
+        Some(_) if length_octets.len() > 1 + DER_MAX_LENGTH_OCTETS => LengthErrorKind::Oversized,
+        Some(l) if l > available_length => LengthErrorKind::ExceedsInput,
+        Some(_) => return None
     };
 
-    Ok((header, tlv_octets))
+    Some(LengthError::new(kind, length_octets, value_len, available_length))
+}
+
+
+/// Reads the header of the TLV at the start of the substrate, accepting the length encodings permitted by BER. The
+/// value length of an indefinite-length header is not known until the end-of-contents octets are found, so it is
+/// returned as None.
+fn parse_ber_header(substrate: &[u8]) -> Result<(usize, Option<usize>, LengthForm), TlvError> {
+    if substrate.len() < 2 {
+        return Err(TlvError::Length(LengthError::new(LengthErrorKind::Truncated, &[], None, 0)));
+    }
+
+    if substrate[0] & 0x1F == 0x1F {
+        return Err(TlvError::Ber("Multi-octet tag numbers are not supported"));
+    }
+
+    match substrate[1] {
+        l @ 0..=0x7F => Ok((2, Some(usize::from(l)), LengthForm::Definite)),
+        0x80 => Ok((2, None, LengthForm::Indefinite)),
+        0xFF => Err(TlvError::Length(LengthError::new(LengthErrorKind::Oversized, &substrate[1..2], None, substrate.len() - 2))),
+        l => {
+            let length_octets_len = usize::from(l & 0x7F);
+
+            if substrate.len() < 2 + length_octets_len {
+                return Err(TlvError::Length(LengthError::new(LengthErrorKind::Truncated, &substrate[1..], None, 0)));
+            }
+
+            let length_octets = &substrate[2..2 + length_octets_len];
+            let oversized = || TlvError::Length(LengthError::new(LengthErrorKind::Oversized, &substrate[1..2 + length_octets_len], None, substrate.len() - 2 - length_octets_len));
+
+            let mut value_len: usize = 0;
+
+            for octet in length_octets {
+                value_len = value_len.checked_mul(256).and_then(|v| v.checked_add(usize::from(*octet))).ok_or_else(oversized)?;
+            }
+
+            let length_form = if value_len < 0x80 || length_octets[0] == 0 {
+                LengthForm::NonMinimal
+            }
+            else {
+                LengthForm::Definite
+            };
+
+            Ok((2 + length_octets_len, Some(value_len), length_form))
+        }
+    }
+}
+
+
+/// Reads the header and the TLV octets of the TLV at the start of the substrate, accepting the length encodings
+/// permitted by BER. The TLV octets of an indefinite-length encoding include the end-of-contents octets. This
+/// function does not require the GIL.
+pub fn parse_ber_tlv(substrate: &[u8]) -> Result<(TlvHeader, &[u8]), TlvError> {
+    parse_ber_tlv_nested(substrate, 0)
+}
+
+
+fn parse_ber_tlv_nested(substrate: &[u8], depth: usize) -> Result<(TlvHeader, &[u8]), TlvError> {
+    let (header_len, value_len, length_form) = parse_ber_header(substrate)?;
+
+    let value_len = match value_len {
+        Some(l) => l,
+        None => {
+            if Asn1Tag::new(substrate[0]).format() != FORMAT_CONSTRUCTED {
+                return Err(TlvError::Ber("Indefinite length in primitive encoding"));
+            }
+
+            if depth >= BER_NESTING_LIMIT {
+                return Err(TlvError::Ber("Exceeded limit on nested indefinite lengths"));
+            }
+
+            // the value consists of complete TLVs up to the end-of-contents octets
+            let mut relative_offset = header_len;
+
+            loop {
+                let remaining = &substrate[relative_offset..];
+
+                if remaining.starts_with(&[0, 0]) {
+                    break;
+                }
+
+                if remaining.is_empty() {
+                    return Err(TlvError::Ber("Missing end-of-contents octets"));
+                }
+
+                let (_, tlv) = parse_ber_tlv_nested(remaining, depth + 1)?;
+
+                relative_offset += tlv.len();
+            }
+
+            relative_offset - header_len
+        }
+    };
+
+    let header = TlvHeader { header_len, value_len, length_form };
+
+    if substrate.len() - header_len < value_len {
+        return Err(TlvError::Length(LengthError::new(LengthErrorKind::ExceedsInput, &substrate[1..header_len], Some(value_len), substrate.len() - header_len)));
+    }
+
+    Ok((header, &substrate[..header.tlv_len()]))
+}
+
+
+/// A segment of a constructed string encoding, which may itself be constructed.
+pub struct StringSegment<'a> {
+    pub offset: usize,
+    pub tag: Asn1Tag,
+    pub header: TlvHeader,
+    pub value: &'a [u8]
+}
+
+
+/// Parses the segments of the value of a constructed BER string encoding. Constructed segments are followed by their
+/// own segments, so the value octets of the string are those of the primitive segments in the returned order. This
+/// function does not require the GIL.
+pub fn parse_string_segments(value: &[u8], base_offset: usize, is_bit_string: bool) -> Result<Vec<StringSegment<'_>>, String> {
+    let mut segments = Vec::new();
+
+    collect_string_segments(value, base_offset, is_bit_string, 0, &mut segments)?;
+
+    Ok(segments)
+}
+
+
+fn collect_string_segments<'a>(value: &'a [u8], base_offset: usize, is_bit_string: bool, depth: usize, segments: &mut Vec<StringSegment<'a>>) -> Result<(), String> {
+    if depth >= BER_NESTING_LIMIT {
+        return Err("Exceeded limit on nested string segments".to_string());
+    }
+
+    // segments of constructed strings other than BIT STRINGs are encoded as OCTET STRINGs
+    let segment_tag_id = if is_bit_string { TAG_ID_BIT_STRING } else { TAG_ID_OCTET_STRING };
+
+    let mut relative_offset = 0;
+
+    while relative_offset < value.len() {
+        let offset = base_offset + relative_offset;
+
+        let (header, tlv) = parse_ber_tlv(&value[relative_offset..]).map_err(|e| e.describe(offset))?;
+
+        let tag = Asn1Tag::new(tlv[0]);
+
+        if tag.class() != CLASS_UNIVERSAL || tag.tag_id() != segment_tag_id {
+            return Err(format!("Invalid string segment near substrate offset {}", offset));
+        }
+
+        let segment_value = &tlv[header.header_len..header.header_len + header.value_len];
+
+        segments.push(StringSegment { offset, tag, header, value: segment_value });
+
+        if tag.format() == FORMAT_CONSTRUCTED {
+            collect_string_segments(segment_value, offset + header.header_len, is_bit_string, depth + 1, segments)?;
+        }
+
+        relative_offset += tlv.len();
+    }
+
+    Ok(())
 }
 
 
-const DECODER_TYPE_BOOLEAN: usize = 1;
-const DECODER_TYPE_INTEGER: usize = 2;
-const DECODER_TYPE_BITSTRING: usize = 3;
-const DECODER_TYPE_OCTETSTRING: usize = 4;
-const DECODER_TYPE_NULL: usize = 5;
-const DECODER_TYPE_OBJECTIDENTIFIER: usize = 6;
-const DECODER_TYPE_ENUMERATED: usize = 10;
-const DECODER_TYPE_UTF8STRING: usize = 12;
-const DECODER_TYPE_SEQUENCE: usize = 16;
-const DECODER_TYPE_SEQUENCEOF: usize = 32;
-const DECODER_TYPE_SETOF: usize = 17;
-const DECODER_TYPE_NUMERICSTRING: usize = 18;
-const DECODER_TYPE_PRINTABLESTRING: usize = 19;
-const DECODER_TYPE_TELETEXSTRING: usize = 20;
-const DECODER_TYPE_VIDEOTEXSTRING: usize = 21;
-const DECODER_TYPE_IA5STRING: usize = 22;
-const DECODER_TYPE_UTCTIME: usize = 23;
-const DECODER_TYPE_GENERALIZEDTIME: usize = 24;
-const DECODER_TYPE_GRAPHICSTRING: usize = 25;
-const DECODER_TYPE_VISIBLESTRING: usize = 26;
-const DECODER_TYPE_UNIVERSALSTRING: usize = 28;
-const DECODER_TYPE_BMPSTRING: usize = 30;
-const DECODER_TYPE_ANY: usize = 98;
-const DECODER_TYPE_CHOICE: usize = 99;
+/// Joins the value octets of the primitive segments of a constructed BIT STRING encoding. Every segment starts with
+/// its trailer length, which must be zero for all segments except the last. The returned octets start with the
+/// trailer length of the last segment.
+pub fn join_bit_string_segments(segments: &[&[u8]]) -> Result<Vec<u8>, String> {
+    let mut octets = Vec::new();
+
+    for (i, segment) in segments.iter().enumerate() {
+        if segment.is_empty() || (i + 1 < segments.len() && segment[0] != 0) {
+            return Err(format!("Invalid BIT STRING segment at index {}", i));
+        }
+
+        if i + 1 == segments.len() {
+            octets.insert(0, segment[0]);
+        }
+
+        octets.extend_from_slice(&segment[1..]);
+    }
+
+    Ok(octets)
+}
+
+
+/// Determines the tag set under which the specified TLV is present in the tag map, descending into explicit tags as
+/// the decoder would. Returns None if the TLV does not match any type in the tag map.
+pub fn find_tag_set_in_tag_map<'py>(m: &NativeHelperModule<'py>, tag_map: &Bound<'py, PyAny>, tlv: &[u8]) -> PyResult<Option<Bound<'py, PyAny>>> {
+    let mut substrate = tlv;
+    let mut tag_set: Option<Bound<'py, PyAny>> = None;
+
+    for _ in 0..NESTED_EXPLICIT_TAG_LIMIT {
+        let tag = Asn1Tag::new(substrate[0]);
+        let pyasn1_tag = m.create_pyasn1_tag(tag)?;
+
+        let new_tag_set = match tag_set {
+            Some(t) => m.create_explicit_pyasn1_tagset(&t, &pyasn1_tag)?,
+            None => m.create_pyasn1_tagset(&pyasn1_tag)?
+        };
+
+        if tag_map.contains(&new_tag_set)? {
+            return Ok(Some(new_tag_set));
+        }
+
+        if tag.format() != FORMAT_CONSTRUCTED || tag.class() == CLASS_UNIVERSAL {
+            return Ok(None);
+        }
+
+        let header_len = match parse_ber_header(substrate) {
+            Ok((l, _, _)) => l,
+            Err(_) => return Ok(None)
+        };
+
+        if substrate.len() <= header_len {
+            return Ok(None);
+        }
+
+        substrate = &substrate[header_len..];
+        tag_set = Some(new_tag_set);
+    }
+
+    Ok(None)
+}
+
+
+pub(crate) const DECODER_TYPE_BOOLEAN: usize = 1;
+pub(crate) const DECODER_TYPE_INTEGER: usize = 2;
+pub(crate) const DECODER_TYPE_BITSTRING: usize = 3;
+pub(crate) const DECODER_TYPE_OCTETSTRING: usize = 4;
+pub(crate) const DECODER_TYPE_NULL: usize = 5;
+pub(crate) const DECODER_TYPE_OBJECTIDENTIFIER: usize = 6;
+pub(crate) const DECODER_TYPE_ENUMERATED: usize = 10;
+pub(crate) const DECODER_TYPE_UTF8STRING: usize = 12;
+pub(crate) const DECODER_TYPE_SEQUENCE: usize = 16;
+pub(crate) const DECODER_TYPE_SEQUENCEOF: usize = 32;
+pub(crate) const DECODER_TYPE_SETOF: usize = 17;
+pub(crate) const DECODER_TYPE_NUMERICSTRING: usize = 18;
+pub(crate) const DECODER_TYPE_PRINTABLESTRING: usize = 19;
+pub(crate) const DECODER_TYPE_TELETEXSTRING: usize = 20;
+pub(crate) const DECODER_TYPE_VIDEOTEXSTRING: usize = 21;
+pub(crate) const DECODER_TYPE_IA5STRING: usize = 22;
+pub(crate) const DECODER_TYPE_UTCTIME: usize = 23;
+pub(crate) const DECODER_TYPE_GENERALIZEDTIME: usize = 24;
+pub(crate) const DECODER_TYPE_GRAPHICSTRING: usize = 25;
+pub(crate) const DECODER_TYPE_VISIBLESTRING: usize = 26;
+pub(crate) const DECODER_TYPE_UNIVERSALSTRING: usize = 28;
+pub(crate) const DECODER_TYPE_BMPSTRING: usize = 30;
+pub(crate) const DECODER_TYPE_ANY: usize = 98;
+pub(crate) const DECODER_TYPE_CHOICE: usize = 99;
 
 
 pub fn decode_asn1_spec_value<'py>(step: DecodeStep<'py>) -> PyResult<Bound<'py, PyAny>> {
     let type_id = step.asn1_spec().getattr(TYPE_ID_ATTR)?;
 
-    let decoder_mappings = &step.module.decoder_mappings;
+    let decoder_mappings = &step.module().decoder_mappings;
     let type_mapping = PyDictMethods::get_item(decoder_mappings, type_id.clone())?;
 
     match type_mapping {
@@ -131,9 +969,25 @@ pub fn decode_asn1_spec_value<'py>(step: DecodeStep<'py>) -> PyResult<Bound<'py,
 
             Err(step.create_error(&format!("ASN.1 specification with type ID of {} is not supported", type_id_u8)))
         },
+        // user-registered decoders are mapped to the callable that decodes the value
+        Some(custom_decoder) if custom_decoder.is_callable() => {
+            if step.context().writes_jer() {
+                return Err(step.create_error(&format!("{} values cannot be encoded in JER", step.asn1_spec().get_type().name()?)));
+            }
+
+            CustomDecoder::new(step, custom_decoder).decode()
+        },
         Some(decoder_id) => {
             let decoder_id_u8: usize = decoder_id.extract()?;
 
+            // the values of primitive types are written to the JER encoding once they are decoded, whereas the
+            // decoders of constructed types write their values while their components are decoded
+            let jer_step = match decoder_id_u8 {
+                DECODER_TYPE_SEQUENCE | DECODER_TYPE_SEQUENCEOF | DECODER_TYPE_SETOF | DECODER_TYPE_CHOICE => None,
+                _ if step.context().writes_jer() => Some(step.clone()),
+                _ => None
+            };
+
             let decoder: &dyn Decoder = match decoder_id_u8 {
                 DECODER_TYPE_BOOLEAN => &BooleanDecoder::new(step),
                 DECODER_TYPE_INTEGER => &IntegerDecoder::new(step, "INTEGER"),
@@ -151,8 +1005,8 @@ pub fn decode_asn1_spec_value<'py>(step: DecodeStep<'py>) -> PyResult<Bound<'py,
                 DECODER_TYPE_TELETEXSTRING => &CharacterStringDecoder::new(step, "TELETEXSTRING"),
                 DECODER_TYPE_VIDEOTEXSTRING => &CharacterStringDecoder::new(step, "VIDEOTEXSTRING"),
                 DECODER_TYPE_IA5STRING => &CharacterStringDecoder::new(step, "IA5STRING"),
-                DECODER_TYPE_UTCTIME => &CharacterStringDecoder::new(step, "UTCTIME"),
-                DECODER_TYPE_GENERALIZEDTIME => &CharacterStringDecoder::new(step, "GENERALIZEDTIME"),
+                DECODER_TYPE_UTCTIME => &TimeDecoder::new(step, "UTCTIME"),
+                DECODER_TYPE_GENERALIZEDTIME => &TimeDecoder::new(step, "GENERALIZEDTIME"),
                 DECODER_TYPE_GRAPHICSTRING => &CharacterStringDecoder::new(step, "GRAPHICSTRING"),
                 DECODER_TYPE_VISIBLESTRING => &CharacterStringDecoder::new(step, "VISIBLESTRING"),
                 DECODER_TYPE_UNIVERSALSTRING => &CharacterStringDecoder::new(step, "UNIVERSALSTRING"),
@@ -163,10 +1017,7 @@ pub fn decode_asn1_spec_value<'py>(step: DecodeStep<'py>) -> PyResult<Bound<'py,
                 _ => return Err(Pyasn1FasderError::new_err("ASN.1 type is unsuppported"))
             };
 
-            match decoder.verify_raw() {
-                Err(e) => return Err(e),
-                Ok(()) => ()
-            };
+            decoder.verify_raw()?;
 
             let decoded_result = decoder.decode();
 
@@ -175,7 +1026,13 @@ pub fn decode_asn1_spec_value<'py>(step: DecodeStep<'py>) -> PyResult<Bound<'py,
                 Ok(decoded) => {
                     match decoder.verify_decoded(&decoded) {
                         Err(e) => Err(e),
-                        Ok(()) => Ok(decoded)
+                        Ok(()) => {
+                            if let Some(s) = jer_step {
+                                s.context().write_jer(|w| w.write_value(&s, decoder_id_u8, &decoded))?;
+                            }
+
+                            Ok(decoded)
+                        }
                     }
                 }
             }
@@ -197,7 +1054,7 @@ pub fn init_module(m: &Bound<PyModule>) -> PyResult<()> {
         type_map.set_item(type_id, decoder_type).unwrap()
     };
 
-    let univ_mod = py.import_bound("pyasn1.type.univ")?;
+    let univ_mod = py.import("pyasn1.type.univ")?;
 
     add_map_entry(&univ_mod, "Boolean", DECODER_TYPE_BOOLEAN);
     add_map_entry(&univ_mod, "Integer", DECODER_TYPE_INTEGER);
@@ -212,7 +1069,7 @@ pub fn init_module(m: &Bound<PyModule>) -> PyResult<()> {
     add_map_entry(&univ_mod, "Any", DECODER_TYPE_ANY);
     add_map_entry(&univ_mod, "Choice", DECODER_TYPE_CHOICE);
 
-    let char_mod = py.import_bound("pyasn1.type.char")?;
+    let char_mod = py.import("pyasn1.type.char")?;
 
     add_map_entry(&char_mod, "NumericString", DECODER_TYPE_NUMERICSTRING);
     add_map_entry(&char_mod, "PrintableString", DECODER_TYPE_PRINTABLESTRING);
@@ -225,7 +1082,7 @@ pub fn init_module(m: &Bound<PyModule>) -> PyResult<()> {
     add_map_entry(&char_mod, "BMPString", DECODER_TYPE_BMPSTRING);
     add_map_entry(&char_mod, "UTF8String", DECODER_TYPE_UTF8STRING);
 
-    let useful_mod = py.import_bound("pyasn1.type.useful")?;
+    let useful_mod = py.import("pyasn1.type.useful")?;
 
     add_map_entry(&useful_mod, "UTCTime", DECODER_TYPE_UTCTIME);
     add_map_entry(&useful_mod, "GeneralizedTime", DECODER_TYPE_GENERALIZEDTIME);
@@ -0,0 +1,143 @@
+use crate::decoder::{DecodeStep, DECODER_TYPE_ANY, DECODER_TYPE_BITSTRING, DECODER_TYPE_BOOLEAN, DECODER_TYPE_ENUMERATED, DECODER_TYPE_GENERALIZEDTIME, DECODER_TYPE_INTEGER, DECODER_TYPE_NULL, DECODER_TYPE_OCTETSTRING, DECODER_TYPE_UTCTIME};
+use pyo3::prelude::PyAnyMethods;
+use pyo3::types::{PyString, PyStringMethods};
+use pyo3::{intern, Bound, PyAny, PyResult};
+use std::fmt::Write;
+
+
+const HEX_DIGITS: &[u8; 16] = b"0123456789ABCDEF";
+const BASE64_ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+
+/// The JSON representation of OCTET STRING and ANY values.
+#[derive(Clone, Copy)]
+pub enum OctetsEncoding {
+    Hex,
+    Base64
+}
+
+
+/// Writes the JER encoding of a value while its DER substrate is decoded. The decoders of constructed types write the
+/// structure of their values and the names of their components, and the values of other types are written once they
+/// are decoded into plain Python objects. The type of every value is determined by the decoder that is mapped to its
+/// type ID, so values of types registered with a pyasn1 decoder class are encoded as that type.
+pub struct JerWriter {
+    octets_encoding: OctetsEncoding,
+    json: String
+}
+
+impl JerWriter {
+    pub fn new(octets_encoding: OctetsEncoding) -> Self {
+        Self { octets_encoding, json: String::new() }
+    }
+
+    pub fn take_json(&mut self) -> String {
+        std::mem::take(&mut self.json)
+    }
+
+    /// Writes a JSON structural character, such as the brackets and separators of objects and arrays.
+    pub fn write_char(&mut self, c: char) {
+        self.json.push(c);
+    }
+
+    /// Writes the name of an object member, preceded by a separator unless it is the first member.
+    pub fn write_member_name(&mut self, name: &str, is_first: bool) {
+        if !is_first {
+            self.json.push(',');
+        }
+
+        self.write_string(name);
+        self.json.push(':');
+    }
+
+    /// Writes the plain Python object into which the value of a primitive type was decoded.
+    pub fn write_value(&mut self, step: &DecodeStep, decoder_id: usize, value: &Bound<PyAny>) -> PyResult<()> {
+        let py = value.py();
+
+        match decoder_id {
+            DECODER_TYPE_BOOLEAN => self.json.push_str(if value.is_truthy()? { "true" } else { "false" }),
+            DECODER_TYPE_INTEGER => self.json.push_str(&value.str()?.to_cow()?),
+            DECODER_TYPE_ENUMERATED => {
+                let name = step.asn1_spec().getattr(intern![py, "namedValues"])?.call_method1(intern![py, "getName"], (value,))?;
+
+                // values without an identifier in the schema are encoded as numbers
+                if name.is_none() {
+                    self.json.push_str(&value.str()?.to_cow()?);
+                }
+                else {
+                    self.write_string(&name.downcast::<PyString>()?.to_cow()?);
+                }
+            },
+            DECODER_TYPE_BITSTRING => {
+                let (octets, unused_bits): (Vec<u8>, usize) = value.extract()?;
+
+                self.json.push_str("{\"value\":");
+                self.write_hex_string(&octets);
+                write!(self.json, ",\"length\":{}}}", octets.len() * 8 - unused_bits).unwrap();
+            },
+            DECODER_TYPE_OCTETSTRING | DECODER_TYPE_ANY => {
+                let octets: Vec<u8> = value.extract()?;
+
+                match self.octets_encoding {
+                    OctetsEncoding::Hex => self.write_hex_string(&octets),
+                    OctetsEncoding::Base64 => self.write_base64_string(&octets)
+                }
+            },
+            DECODER_TYPE_NULL => self.json.push_str("null"),
+            // times are decoded into datetime objects, so their string values are taken from the substrate
+            DECODER_TYPE_UTCTIME | DECODER_TYPE_GENERALIZEDTIME => self.write_string(&String::from_utf8_lossy(&step.string_value_octets()?)),
+            // object identifiers and character strings are decoded into their string values
+            _ => self.write_string(&value.str()?.to_cow()?)
+        }
+
+        Ok(())
+    }
+
+    fn write_string(&mut self, value: &str) {
+        self.json.push('"');
+
+        for c in value.chars() {
+            match c {
+                '"' => self.json.push_str("\\\""),
+                '\\' => self.json.push_str("\\\\"),
+                '\n' => self.json.push_str("\\n"),
+                '\r' => self.json.push_str("\\r"),
+                '\t' => self.json.push_str("\\t"),
+                c if u32::from(c) < 0x20 => write!(self.json, "\\u{:04x}", u32::from(c)).unwrap(),
+                c => self.json.push(c)
+            }
+        }
+
+        self.json.push('"');
+    }
+
+    fn write_hex_string(&mut self, octets: &[u8]) {
+        self.json.push('"');
+
+        for o in octets {
+            self.json.push(char::from(HEX_DIGITS[usize::from(o >> 4)]));
+            self.json.push(char::from(HEX_DIGITS[usize::from(o & 0x0F)]));
+        }
+
+        self.json.push('"');
+    }
+
+    fn write_base64_string(&mut self, octets: &[u8]) {
+        self.json.push('"');
+
+        for chunk in octets.chunks(3) {
+            let group = chunk.iter().enumerate().fold(0u32, |acc, (i, o)| acc | u32::from(*o) << (16 - 8 * i));
+
+            for i in 0..4 {
+                if i <= chunk.len() {
+                    self.json.push(char::from(BASE64_ALPHABET[((group >> (18 - 6 * i)) & 0x3F) as usize]));
+                }
+                else {
+                    self.json.push('=');
+                }
+            }
+        }
+
+        self.json.push('"');
+    }
+}
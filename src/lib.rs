@@ -1,47 +1,98 @@
-mod tag;
-mod decoder;
-mod asn1_type;
+pub mod tag;
+pub mod decoder;
+pub mod asn1_type;
+pub mod path;
+pub mod validate;
+pub mod canonicalize;
+pub mod time;
+pub mod jer;
+pub mod navigate;
 
 use std::clone::Clone;
+use std::rc::Rc;
 use pyo3::prelude::*;
+#[cfg(any(Py_3_11, not(Py_LIMITED_API)))]
+use pyo3::buffer::PyBuffer;
+use pyo3::exceptions::{PyTypeError, PyValueError};
 use pyo3::intern;
-use pyo3::types::{PyAny, PyDict};
-use crate::decoder::{decode_asn1_spec_value, DecodeStep};
-use crate::tag::{Asn1Tag, CLASS_UNIVERSAL};
+use pyo3::types::{PyAny, PyBytes, PyDict, PyList, PyMemoryView, PySet, PyString, PyTuple};
+use crate::asn1_type::{AnyDecoder, Decoder};
+use crate::decoder::{decode_asn1_spec_value, DecodeContext, DecodeOptions, DecodeStep, EncodingRules, TlvHeader};
+use crate::jer::OctetsEncoding;
+use crate::path::{ComponentPath, PathSegment};
+use crate::tag::{Asn1Tag, CLASS_UNIVERSAL, FORMAT_CONSTRUCTED};
 
 
 pyo3::import_exception!(pyasn1_fasder.error, Pyasn1FasderError);
+pyo3::import_exception!(pyasn1_fasder.error, TlvLengthError);
+pyo3::import_exception!(pyasn1_fasder.error, IntegerRuleError);
 
 
 const TAGSET_ATTR: &str = "tagSet";
 const TAGMAP_ATTR: &str = "tagMap";
 const TYPE_MAP: &str = "_TYPE_MAP";
 const HELPER_MODULE_ATTR: &str = "_HELPER";
+const HELPER_STATE_ATTR: &str = "_HELPER_STATE";
+const OPAQUE_SPEC: &str = "_OPAQUE_SPEC";
 
 const NESTED_EXPLICIT_TAG_LIMIT: usize = 4;
+const OID_CACHE_LIMIT: usize = 1024;
+const EXPLICIT_TAGSET_CACHE_LIMIT: usize = 1024;
 
 
+/// The objects of the helper module that are used by the decoder. The caches are dicts that are shared by all threads;
+/// on free-threaded builds, threads that miss a cache at the same time each create and store an equal value, which is
+/// benign.
 #[derive(Clone)]
 pub struct NativeHelperModule<'py> {
     module: Bound<'py, PyModule>,
     tag_cls: Bound<'py, PyAny>,
     tagset_cls: Bound<'py, PyAny>,
     tagmap_cls: Bound<'py, PyAny>,
+    position_cls: Bound<'py, PyAny>,
+    finding_cls: Bound<'py, PyAny>,
     tag_cache: Bound<'py, PyDict>,
     tagset_cache: Bound<'py, PyDict>,
+    explicit_tagset_cache: Bound<'py, PyDict>,
+    oid_cache: Bound<'py, PyDict>,
     decoder_mappings: Bound<'py, PyDict>,
 }
 
 impl<'py> NativeHelperModule<'py> {
+    /// Returns the helper state of the specified module, which is created when the module is initialized.
     pub fn new(base_module: &Bound<'py, PyModule>) -> PyResult<Self> {
         let py = base_module.py();
 
+        let state_attr = base_module.getattr(intern![py, HELPER_STATE_ATTR])?;
+        let state = state_attr.downcast_exact::<HelperState>()?.get();
+
+        Ok(Self {
+            module: state.module.bind(py).clone(),
+            tag_cls: state.tag_cls.bind(py).clone(),
+            tagset_cls: state.tagset_cls.bind(py).clone(),
+            tagmap_cls: state.tagmap_cls.bind(py).clone(),
+            position_cls: state.position_cls.bind(py).clone(),
+            finding_cls: state.finding_cls.bind(py).clone(),
+            tag_cache: state.tag_cache.bind(py).clone(),
+            tagset_cache: state.tagset_cache.bind(py).clone(),
+            explicit_tagset_cache: state.explicit_tagset_cache.bind(py).clone(),
+            oid_cache: state.oid_cache.bind(py).clone(),
+            decoder_mappings: state.decoder_mappings.bind(py).clone(),
+        })
+    }
+
+    /// Looks up the objects of the helper module of the specified module.
+    fn load(base_module: &Bound<'py, PyModule>) -> PyResult<Self> {
+        let py = base_module.py();
+
         let module_attr = base_module.getattr(intern![py, HELPER_MODULE_ATTR])?;
         let module: Bound<PyModule> = module_attr.downcast_exact()?.clone();
 
         let tag_cls = module.getattr(intern![py, "_TAG_CLS"])?;
         let tagset_cls = module.getattr(intern![py, "_TAGSET_CLS"])?;
         let tagmap_cls = module.getattr(intern![py, "_TAGMAP_CLS"])?;
+        let position_cls = module.getattr(intern![py, "_POSITION_CLS"])?;
+        let finding_cls = module.getattr(intern![py, "_FINDING_CLS"])?;
 
         let tag_cache_attr = module.getattr(intern![py, "_TAG_CACHE"])?;
         let tag_cache = tag_cache_attr.downcast_exact()?.clone();
@@ -49,38 +100,133 @@ impl<'py> NativeHelperModule<'py> {
         let tagset_cache_attr = module.getattr(intern![py, "_TAGSET_CACHE"])?;
         let tagset_cache = tagset_cache_attr.downcast_exact()?.clone();
 
+        let explicit_tagset_cache_attr = module.getattr(intern![py, "_EXPLICIT_TAGSET_CACHE"])?;
+        let explicit_tagset_cache = explicit_tagset_cache_attr.downcast_exact()?.clone();
+
+        let oid_cache_attr = module.getattr(intern![py, "_OID_CACHE"])?;
+        let oid_cache = oid_cache_attr.downcast_exact()?.clone();
+
         let decoder_mappings_attr = module.getattr(intern![py, TYPE_MAP])?;
         let decoder_mappings = decoder_mappings_attr.downcast_exact()?.clone();
 
-        Ok(Self { module, tag_cls, tagset_cls, tagmap_cls, tag_cache, tagset_cache, decoder_mappings })
+        Ok(Self {
+            module, tag_cls, tagset_cls, tagmap_cls, position_cls, finding_cls, tag_cache, tagset_cache, explicit_tagset_cache,
+            oid_cache, decoder_mappings
+        })
+    }
+
+    pub fn create_pyasn1_tag(&self, tag: Asn1Tag) -> PyResult<Bound<'py, PyAny>> {
+        if let Some(cached_tag) = self.tag_cache.get_item(u8::from(tag))? {
+            return Ok(cached_tag)
+        }
+
+        // tags are cached by their identifier octet, so at most 256 tags are cached
+        let pyasn1_tag = self.tag_cls.call((tag.class(), tag.format(), tag.tag_id()), None)?;
+
+        self.tag_cache.set_item(u8::from(tag), &pyasn1_tag)?;
+
+        Ok(pyasn1_tag)
     }
 
-    pub fn create_pyasn1_tag(&self, tag: Asn1Tag) -> PyResult<Bound<PyAny>> {
-        if tag.class() == CLASS_UNIVERSAL {
-            let cached_tag = self.tag_cache.get_item(u8::from(tag))?;
+    /// Returns the tag set with the specified tag, which must have been created by `create_pyasn1_tag`.
+    pub fn create_pyasn1_tagset(&self, pyasn1_tag: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyAny>> {
+        if let Some(cached_tagset) = self.tagset_cache.get_item(pyasn1_tag)? {
+            return Ok(cached_tagset)
+        }
 
-            if cached_tag.is_some() {
-                return Ok(cached_tag.unwrap())
-            }
+        let tagset = self.tagset_cls.call(((), pyasn1_tag), None)?;
+
+        self.tagset_cache.set_item(pyasn1_tag, &tagset)?;
+
+        Ok(tagset)
+    }
+
+    /// Returns the tag set of an explicitly tagged value, which is the specified tag set of its outer tags with the
+    /// specified tag of the inner TLV added.
+    pub fn create_explicit_pyasn1_tagset(&self, tag_set: &Bound<'py, PyAny>, pyasn1_tag: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyAny>> {
+        let py = tag_set.py();
+
+        let key = PyTuple::new(py, [tag_set, pyasn1_tag])?;
+
+        if let Some(cached_tagset) = self.explicit_tagset_cache.get_item(&key)? {
+            return Ok(cached_tagset)
+        }
+
+        let explicit_tagset = tag_set.call_method1(intern![py, "__radd__"], (pyasn1_tag,))?;
+
+        if self.explicit_tagset_cache.len() < EXPLICIT_TAGSET_CACHE_LIMIT {
+            self.explicit_tagset_cache.set_item(key, &explicit_tagset)?;
         }
 
-        self.tag_cls.call((tag.class(), tag.format(), tag.tag_id()), None)
+        Ok(explicit_tagset)
     }
 
-    pub fn create_pyasn1_tagset(&self, pyasn1_tag: &Bound<PyAny>, tag: Asn1Tag) -> PyResult<Bound<'py, PyAny>> {
-        if tag.class() == CLASS_UNIVERSAL {
-            let cached_tagset = self.tagset_cache.get_item(pyasn1_tag)?;
+    /// Returns the tuple of arcs of the OBJECT IDENTIFIER with the specified value octets, which is created using the
+    /// specified function if it is not cached. Only valid value octets are cached, so cached values need not be
+    /// checked again.
+    pub fn get_oid_arcs<F>(&self, value_octets: &Bound<'py, PyBytes>, create: F) -> PyResult<Bound<'py, PyAny>>
+        where F: FnOnce() -> PyResult<Bound<'py, PyAny>>
+    {
+        if let Some(arcs) = self.oid_cache.get_item(value_octets)? {
+            return Ok(arcs);
+        }
 
-            if cached_tagset.is_some() {
-                return Ok(cached_tagset.unwrap())
-            }
+        let arcs = create()?;
+
+        if self.oid_cache.len() < OID_CACHE_LIMIT {
+            self.oid_cache.set_item(value_octets, &arcs)?;
         }
 
-        self.tagset_cls.call(((), pyasn1_tag), None)
+        Ok(arcs)
     }
 }
 
 
+/// The objects of the helper module that are used by the decoder, which are looked up once when the module is
+/// initialized. The state is stored as an attribute of the module object rather than in a static, so that
+/// `reset_helper_state` can replace it. This does not make the module usable in subinterpreters, as PyO3 only allows it
+/// to be imported in a single interpreter (see `pyasn1_fasder`).
+#[pyclass(frozen, module = "pyasn1_fasder._native")]
+struct HelperState {
+    module: Py<PyModule>,
+    tag_cls: PyObject,
+    tagset_cls: PyObject,
+    tagmap_cls: PyObject,
+    position_cls: PyObject,
+    finding_cls: PyObject,
+    tag_cache: Py<PyDict>,
+    tagset_cache: Py<PyDict>,
+    explicit_tagset_cache: Py<PyDict>,
+    oid_cache: Py<PyDict>,
+    decoder_mappings: Py<PyDict>,
+}
+
+impl HelperState {
+    fn new(m: NativeHelperModule) -> Self {
+        Self {
+            module: m.module.unbind(),
+            tag_cls: m.tag_cls.unbind(),
+            tagset_cls: m.tagset_cls.unbind(),
+            tagmap_cls: m.tagmap_cls.unbind(),
+            position_cls: m.position_cls.unbind(),
+            finding_cls: m.finding_cls.unbind(),
+            tag_cache: m.tag_cache.unbind(),
+            tagset_cache: m.tagset_cache.unbind(),
+            explicit_tagset_cache: m.explicit_tagset_cache.unbind(),
+            oid_cache: m.oid_cache.unbind(),
+            decoder_mappings: m.decoder_mappings.unbind(),
+        }
+    }
+}
+
+
+fn initialize_helper_state(m: &Bound<PyModule>) -> PyResult<()> {
+    let state = HelperState::new(NativeHelperModule::load(m)?);
+
+    m.setattr(intern![m.py(), HELPER_STATE_ATTR], Bound::new(m.py(), state)?)
+}
+
+
 fn decode_explicit<'call, 'py>(step: DecodeStep<'py>) -> PyResult<Bound<'py, PyAny>> where 'py: 'call {
     // stop recursion if we've already descended multiple times
     if step.tag_set().len()? >= NESTED_EXPLICIT_TAG_LIMIT {
@@ -92,10 +238,10 @@ fn decode_explicit<'call, 'py>(step: DecodeStep<'py>) -> PyResult<Bound<'py, PyA
     let first_tag_class = first_tag.get_item(0)?;
 
     if first_tag_fmt.eq(tag::FORMAT_CONSTRUCTED)? && first_tag_class.ne(CLASS_UNIVERSAL)? {
-        let new_offset = step.offset() + usize::try_from(step.header().length).unwrap();
+        let new_offset = step.offset() + step.header_len();
 
-        decode_der_rec(step.module().clone(), step.value_substrate(), step.asn1_spec().clone(), Some(
-            step.tag_set().clone()), new_offset)
+        decode_der_rec(step.context().clone(), step.value_substrate(), step.asn1_spec().clone(), Some(
+            step.tag_set().clone()), new_offset, step.path().clone())
     }
     else {
         Err(step.create_error("Substrate does not match ASN.1 specification"))
@@ -103,7 +249,7 @@ fn decode_explicit<'call, 'py>(step: DecodeStep<'py>) -> PyResult<Bound<'py, PyA
 }
 
 
-pub fn get_chosen_spec<'py>(m: &NativeHelperModule, asn1_spec: &pyo3::Bound<'py, PyAny>, substrate_tag_set: &Bound<PyAny>) -> PyResult<Option<Bound<'py, PyAny>>> {
+pub fn get_chosen_spec<'py>(m: &NativeHelperModule, asn1_spec: &pyo3::Bound<'py, PyAny>, substrate_tag_set: &Bound<'py, PyAny>) -> PyResult<Option<Bound<'py, PyAny>>> {
     let py = asn1_spec.py();
 
     if asn1_spec.get_type().is(&m.tagmap_cls) {
@@ -121,70 +267,478 @@ pub fn get_chosen_spec<'py>(m: &NativeHelperModule, asn1_spec: &pyo3::Bound<'py,
 }
 
 
-fn decode_der_rec<'py>(m: NativeHelperModule<'py>, substrate: &'py [u8], asn1_spec: Bound<'py, PyAny>, tag_set: Option<Bound<'py, PyAny>>, offset: usize) -> PyResult<Bound<'py, PyAny>> {
+fn decode_der_rec<'py>(ctx: Rc<DecodeContext<'py>>, substrate: &'py [u8], asn1_spec: Bound<'py, PyAny>, tag_set: Option<Bound<'py, PyAny>>, offset: usize, path: ComponentPath<'py>) -> PyResult<Bound<'py, PyAny>> {
+    // the visitor is invoked once for every component, after the outermost TLV of the component is decoded
+    if tag_set.is_none() && ctx.has_visitor() {
+        let decoded = decode_tlv(ctx.clone(), substrate, asn1_spec.clone(), None, offset, path.clone())?;
+
+        // optional and defaulted components are decoded with the tag map of their position, so the specification of
+        // the matching component is visited
+        let m = ctx.module();
+
+        let visited_spec = if asn1_spec.get_type().is(&m.tagmap_cls) {
+            match decoder::find_tag_set_in_tag_map(m, &asn1_spec, substrate)? {
+                Some(t) => asn1_spec.get_item(t)?,
+                None => asn1_spec
+            }
+        }
+        else {
+            asn1_spec
+        };
+
+        ctx.visit(&path, &visited_spec, &decoded, offset, substrate)?;
+
+        return Ok(decoded);
+    }
+
+    decode_tlv(ctx, substrate, asn1_spec, tag_set, offset, path)
+}
+
+
+fn decode_tlv<'py>(ctx: Rc<DecodeContext<'py>>, substrate: &'py [u8], asn1_spec: Bound<'py, PyAny>, tag_set: Option<Bound<'py, PyAny>>, offset: usize, path: ComponentPath<'py>) -> PyResult<Bound<'py, PyAny>> {
     if asn1_spec.is_none() {
         return Err(Pyasn1FasderError::new_err(format!("No ASN.1 specification near substrate offset {}", offset)));
     }
 
-    let (header, tlv_octets) = match decoder::read_tlv(substrate, offset) {
-        Ok(header_and_octets) => header_and_octets,
-        Err(e) => return Err(e)
-    };
+    let (header, tlv_octets) = ctx.read_tlv(substrate, offset)?;
 
     if substrate.len() != tlv_octets.len() {
         return Err(Pyasn1FasderError::new_err(format!("{} trailing octet(s) after TLV near substrate offset {}", substrate.len() - tlv_octets.len(), offset)));
     }
 
+    ctx.check_length_form(Asn1Tag::new(substrate[0]), header, offset)?;
+
+    let m = ctx.module();
+
+    // record the position of the outermost TLV of the component
+
+    if tag_set.is_none() {
+        ctx.record_position(&path, offset, header)?;
+
+        if ctx.is_opaque(&path, &asn1_spec, substrate)? {
+            return decode_opaque(ctx.clone(), substrate, header, asn1_spec, offset, path);
+        }
+    }
+
     // initialize tag and tagSet from decoded substrate
 
     let substrate_tag = m.create_pyasn1_tag(Asn1Tag::new(substrate[0]))?;
 
     let new_tag_set = match tag_set {
-        Some(pyasn1_tagset) => pyasn1_tagset.call_method(intern![m.module.py(), "__radd__"], (substrate_tag,), None)?,
-        None => m.create_pyasn1_tagset(&substrate_tag, Asn1Tag::new(substrate[0]))?.to_owned()
+        Some(pyasn1_tagset) => m.create_explicit_pyasn1_tagset(&pyasn1_tagset, &substrate_tag)?,
+        None => m.create_pyasn1_tagset(&substrate_tag)?
     };
 
     // determine ASN.1 spec to use for value decoding
 
-    let chosen_spec = match get_chosen_spec(&m, &asn1_spec, &new_tag_set) {
-        Ok(None) => return decode_explicit(DecodeStep::new(m.clone(), substrate, header, asn1_spec.clone(), new_tag_set, offset)),
+    let chosen_spec = match get_chosen_spec(m, &asn1_spec, &new_tag_set) {
+        Ok(None) => return decode_explicit(DecodeStep::new(ctx.clone(), substrate, header, asn1_spec.clone(), new_tag_set, offset, path)),
         Ok(Some(c)) => c,
         Err(e) => return Err(e),
     };
 
     // create a new step with the chosen ASN.1 spec
 
-    let step = DecodeStep::new(m.clone(), substrate, header, chosen_spec, new_tag_set, offset);
+    let step = DecodeStep::new(ctx.clone(), substrate, header, chosen_spec, new_tag_set, offset, path);
 
     // find decoder for chosen ASN.1 spec and decode substrate value
     decode_asn1_spec_value(step)
 }
 
 
+/// Decodes an opaque component into an ANY value that holds the octets of its outermost TLV, including any explicit
+/// tags. The tags are matched against the ASN.1 specification and the framing of nested TLVs is checked.
+fn decode_opaque<'py>(ctx: Rc<DecodeContext<'py>>, substrate: &'py [u8], header: TlvHeader, asn1_spec: Bound<'py, PyAny>, offset: usize, path: ComponentPath<'py>) -> PyResult<Bound<'py, PyAny>> {
+    let py = asn1_spec.py();
+    let m = ctx.module();
+
+    let tag_map = if asn1_spec.get_type().is(&m.tagmap_cls) { asn1_spec.clone() } else { asn1_spec.getattr(intern![py, TAGMAP_ATTR])? };
+
+    let substrate_tag = m.create_pyasn1_tag(Asn1Tag::new(substrate[0]))?;
+    let tag_set = m.create_pyasn1_tagset(&substrate_tag)?;
+
+    if decoder::find_tag_set_in_tag_map(m, &tag_map, substrate)?.is_none() {
+        return Err(DecodeStep::new(ctx.clone(), substrate, header, asn1_spec, tag_set, offset, path).create_error("Substrate does not match ASN.1 specification"));
+    }
+
+    if Asn1Tag::new(substrate[0]).format() == FORMAT_CONSTRUCTED {
+        ctx.check_nested_framing(&substrate[header.header_len..header.header_len + header.value_len], offset + header.header_len)?;
+    }
+
+    let opaque_spec = m.module.getattr(intern![py, OPAQUE_SPEC])?;
+
+    AnyDecoder::new(DecodeStep::new(ctx.clone(), substrate, header, opaque_spec, tag_set, offset, path)).decode()
+}
+
+
+/// The octets of a substrate. Byte strings and, where the buffer API is available (Python 3.11 or later with the
+/// stable ABI), read-only memoryviews over byte strings are borrowed; other buffers, including writable ones such as
+/// bytearrays and mmaps, are copied into a byte string, as their contents could be changed by Python code or by other
+/// threads while the substrate is decoded.
+enum SubstrateOctets<'py> {
+    Bytes(Bound<'py, PyBytes>),
+    #[cfg(any(Py_3_11, not(Py_LIMITED_API)))]
+    Buffer(PyBuffer<u8>)
+}
+
+impl SubstrateOctets<'_> {
+    fn as_bytes(&self) -> &[u8] {
+        match self {
+            SubstrateOctets::Bytes(b) => b.as_bytes(),
+            #[cfg(any(Py_3_11, not(Py_LIMITED_API)))]
+            SubstrateOctets::Buffer(b) if b.len_bytes() == 0 => &[],
+            #[cfg(any(Py_3_11, not(Py_LIMITED_API)))]
+            // SAFETY: the buffer is a C-contiguous buffer of octets that is exported by a memoryview over a byte
+            // string, whose contents cannot change, and it remains exported until it is dropped. The slice can
+            // therefore be read while Python callbacks run and while the GIL is released.
+            SubstrateOctets::Buffer(b) => unsafe { std::slice::from_raw_parts(b.buf_ptr() as *const u8, b.len_bytes()) }
+        }
+    }
+}
+
+
+/// Returns the buffer of the substrate if the substrate is a read-only memoryview over a byte string, so that the
+/// buffer can be borrowed.
+#[cfg(any(Py_3_11, not(Py_LIMITED_API)))]
+fn get_immutable_buffer(substrate: &Bound<PyAny>) -> PyResult<Option<PyBuffer<u8>>> {
+    let py = substrate.py();
+
+    if !substrate.is_instance_of::<PyMemoryView>() || !substrate.getattr(intern![py, "obj"])?.is_instance_of::<PyBytes>() {
+        return Ok(None);
+    }
+
+    match PyBuffer::<u8>::get(substrate) {
+        Ok(buffer) if buffer.readonly() && buffer.is_c_contiguous() => Ok(Some(buffer)),
+        _ => Ok(None)
+    }
+}
+
+
+fn get_substrate_octets<'py>(substrate: &Bound<'py, PyAny>) -> PyResult<SubstrateOctets<'py>> {
+    if let Ok(b) = substrate.downcast::<PyBytes>() {
+        return Ok(SubstrateOctets::Bytes(b.clone()));
+    }
+
+    #[cfg(any(Py_3_11, not(Py_LIMITED_API)))]
+    if let Some(buffer) = get_immutable_buffer(substrate)? {
+        return Ok(SubstrateOctets::Buffer(buffer));
+    }
+
+    Ok(SubstrateOctets::Bytes(substrate.py().get_type::<PyBytes>().call1((substrate,))?.downcast_into::<PyBytes>()?))
+}
+
+
+fn get_utc_time_pivot_year(year: i64) -> PyResult<u16> {
+    // the years of the century that starts with the pivot year must be representable as datetime objects
+    match u16::try_from(year) {
+        Ok(y) if (1..=9900).contains(&y) => Ok(y),
+        _ => Err(PyValueError::new_err(format!("Invalid UTCTime pivot year: {}", year)))
+    }
+}
+
+
+fn create_decode_context<'py>(m: &Bound<'py, PyModule>, substrate: &Bound<'py, PyAny>, memoryview_values: bool, mut options: DecodeOptions<'py>) -> PyResult<Rc<DecodeContext<'py>>> {
+    if memoryview_values {
+        options.substrate_view = Some(PyMemoryView::from(substrate)?);
+    }
+
+    Ok(Rc::new(DecodeContext::new(NativeHelperModule::new(m)?, options)))
+}
+
+
+/// Sets the opaque components of the decode options, which are split into the set of component paths and the tuple
+/// of pyasn1 types. Components that are not tuples are pyasn1 type classes.
+fn with_opaque_components<'py>(mut options: DecodeOptions<'py>, opaque_components: Option<Bound<'py, PyList>>) -> PyResult<DecodeOptions<'py>> {
+    if let Some(opaque_components) = opaque_components {
+        let py = opaque_components.py();
+
+        let opaque_paths = PySet::empty(py)?;
+        let mut opaque_types = Vec::new();
+
+        for component in opaque_components.iter() {
+            if component.is_instance_of::<PyTuple>() {
+                opaque_paths.add(component)?;
+            }
+            else {
+                opaque_types.push(component);
+            }
+        }
+
+        options.opaque_paths = Some(opaque_paths);
+        options.opaque_types = Some(PyTuple::new(py, opaque_types)?);
+    }
+
+    Ok(options)
+}
+
+
+fn decode_substrate<'py>(m: &Bound<'py, PyModule>, substrate: &Bound<'py, PyAny>, asn1_spec: &Bound<'py, PyAny>, memoryview_values: bool, options: DecodeOptions<'py>) -> PyResult<Bound<'py, PyAny>> {
+    let py = m.py();
+
+    let substrate_bytes = get_substrate_octets(substrate)?;
+
+    let ctx = create_decode_context(m, substrate, memoryview_values, options)?;
+
+    let decoded = decode_der_rec(ctx, substrate_bytes.as_bytes(), asn1_spec.clone(), None, 0, ComponentPath::root())?;
+
+    Ok(decoded.unbind().into_bound(py))
+}
+
+
+#[pyfunction]
+#[pyo3(pass_module, signature = (substrate, asn1_spec, *, memoryview_values = false, positions = None, utc_time_pivot_year = i64::from(time::DEFAULT_UTC_TIME_PIVOT_YEAR), opaque_components = None, integer_rules = None, non_empty_components = None, warning_callback = None, visitor = None))]
+#[allow(clippy::too_many_arguments)]
+fn decode_der<'py>(m: &Bound<'py, PyModule>, substrate: &Bound<'py, PyAny>, asn1_spec: &Bound<'py, PyAny>, memoryview_values: bool, positions: Option<Bound<'py, PyDict>>, utc_time_pivot_year: i64, opaque_components: Option<Bound<'py, PyList>>, integer_rules: Option<Bound<'py, PyDict>>, non_empty_components: Option<Bound<'py, PySet>>, warning_callback: Option<Bound<'py, PyAny>>, visitor: Option<Bound<'py, PyAny>>) -> PyResult<Bound<'py, PyAny>> {
+    let options = with_opaque_components(DecodeOptions { positions, utc_time_pivot_year: Some(get_utc_time_pivot_year(utc_time_pivot_year)?), integer_rules, non_empty_paths: non_empty_components, warning_callback, visitor, ..Default::default() }, opaque_components)?;
+
+    decode_substrate(m, substrate, asn1_spec, memoryview_values, options)
+}
+
+
+#[pyfunction]
+#[pyo3(pass_module, signature = (substrate, asn1_spec, *, memoryview_values = false, positions = None, utc_time_pivot_year = i64::from(time::DEFAULT_UTC_TIME_PIVOT_YEAR), opaque_components = None, integer_rules = None, non_empty_components = None, warning_callback = None))]
+#[allow(clippy::too_many_arguments)]
+fn decode_ber<'py>(m: &Bound<'py, PyModule>, substrate: &Bound<'py, PyAny>, asn1_spec: &Bound<'py, PyAny>, memoryview_values: bool, positions: Option<Bound<'py, PyDict>>, utc_time_pivot_year: i64, opaque_components: Option<Bound<'py, PyList>>, integer_rules: Option<Bound<'py, PyDict>>, non_empty_components: Option<Bound<'py, PySet>>, warning_callback: Option<Bound<'py, PyAny>>) -> PyResult<(Bound<'py, PyAny>, Bound<'py, PyList>)> {
+    let deviations = PyList::empty(m.py());
+
+    let options = with_opaque_components(DecodeOptions { encoding_rules: EncodingRules::Ber, positions, deviations: Some(deviations.clone()), utc_time_pivot_year: Some(get_utc_time_pivot_year(utc_time_pivot_year)?), integer_rules, non_empty_paths: non_empty_components, warning_callback, ..Default::default() }, opaque_components)?;
+
+    let decoded = decode_substrate(m, substrate, asn1_spec, memoryview_values, options)?;
+
+    Ok((decoded, deviations))
+}
+
+
+#[pyfunction]
+#[pyo3(pass_module, signature = (substrate, asn1_spec, *, memoryview_values = false, positions = None, utc_time_pivot_year = i64::from(time::DEFAULT_UTC_TIME_PIVOT_YEAR), opaque_components = None, integer_rules = None, non_empty_components = None, warning_callback = None))]
+#[allow(clippy::too_many_arguments)]
+fn decode_cer<'py>(m: &Bound<'py, PyModule>, substrate: &Bound<'py, PyAny>, asn1_spec: &Bound<'py, PyAny>, memoryview_values: bool, positions: Option<Bound<'py, PyDict>>, utc_time_pivot_year: i64, opaque_components: Option<Bound<'py, PyList>>, integer_rules: Option<Bound<'py, PyDict>>, non_empty_components: Option<Bound<'py, PySet>>, warning_callback: Option<Bound<'py, PyAny>>) -> PyResult<Bound<'py, PyAny>> {
+    let options = with_opaque_components(DecodeOptions { encoding_rules: EncodingRules::Cer, positions, utc_time_pivot_year: Some(get_utc_time_pivot_year(utc_time_pivot_year)?), integer_rules, non_empty_paths: non_empty_components, warning_callback, ..Default::default() }, opaque_components)?;
+
+    decode_substrate(m, substrate, asn1_spec, memoryview_values, options)
+}
+
+
+#[pyfunction]
+#[pyo3(pass_module, signature = (substrate, asn1_spec, *, memoryview_values = false, positions = None, utc_time_pivot_year = i64::from(time::DEFAULT_UTC_TIME_PIVOT_YEAR), opaque_components = None, integer_rules = None, non_empty_components = None, warning_callback = None))]
+#[allow(clippy::too_many_arguments)]
+fn decode_der_native<'py>(m: &Bound<'py, PyModule>, substrate: &Bound<'py, PyAny>, asn1_spec: &Bound<'py, PyAny>, memoryview_values: bool, positions: Option<Bound<'py, PyDict>>, utc_time_pivot_year: i64, opaque_components: Option<Bound<'py, PyList>>, integer_rules: Option<Bound<'py, PyDict>>, non_empty_components: Option<Bound<'py, PySet>>, warning_callback: Option<Bound<'py, PyAny>>) -> PyResult<Bound<'py, PyAny>> {
+    let options = with_opaque_components(DecodeOptions { positions, native_values: true, utc_time_pivot_year: Some(get_utc_time_pivot_year(utc_time_pivot_year)?), integer_rules, non_empty_paths: non_empty_components, warning_callback, ..Default::default() }, opaque_components)?;
+
+    decode_substrate(m, substrate, asn1_spec, memoryview_values, options)
+}
+
+
+#[pyfunction]
+#[pyo3(pass_module, signature = (substrate, asn1_spec, path, *, memoryview_values = false, utc_time_pivot_year = i64::from(time::DEFAULT_UTC_TIME_PIVOT_YEAR)))]
+fn decode_der_path<'py>(m: &Bound<'py, PyModule>, substrate: &Bound<'py, PyAny>, asn1_spec: &Bound<'py, PyAny>, path: &Bound<'py, PyTuple>, memoryview_values: bool, utc_time_pivot_year: i64) -> PyResult<Option<(Bound<'py, PyAny>, Bound<'py, PyAny>)>> {
+    let py = m.py();
+
+    let mut component_path = ComponentPath::root();
+    let mut segments = Vec::new();
+
+    for item in path.iter() {
+        let segment = match item.downcast_into::<PyString>() {
+            Ok(name) => PathSegment::Name(name),
+            Err(e) => match e.into_inner().extract::<usize>() {
+                Ok(index) => PathSegment::Index(index),
+                Err(_) => return Err(PyTypeError::new_err("Path segments must be component names or indexes"))
+            }
+        };
+
+        component_path = component_path.child(segment.clone());
+        segments.push(segment);
+    }
+
+    let substrate_bytes = get_substrate_octets(substrate)?;
+
+    // the position of the component is recorded as it is decoded
+    let positions = PyDict::new(py);
+
+    let options = DecodeOptions { positions: Some(positions.clone()), utc_time_pivot_year: Some(get_utc_time_pivot_year(utc_time_pivot_year)?), ..Default::default() };
+
+    let ctx = create_decode_context(m, substrate, memoryview_values, options)?;
+
+    let component = match navigate::locate_component(&ctx, substrate_bytes.as_bytes(), asn1_spec, &segments)? {
+        navigate::Location::Present(c) => c,
+        // absent components with default values are returned as decode_der returns them, without a position
+        navigate::Location::Defaulted(default_value) => return Ok(Some((default_value, py.None().into_bound(py)))),
+        navigate::Location::Absent => return Ok(None)
+    };
+
+    let decoded = decode_der_rec(ctx, component.tlv, component.asn1_spec, None, component.offset, component_path.clone())?;
+
+    let position = match positions.get_item(component_path.to_py_tuple(py)?)? {
+        Some(p) => p,
+        None => return Err(Pyasn1FasderError::new_err(format!("Unable to determine position of component near substrate offset {}", component.offset)))
+    };
+
+    Ok(Some((decoded.unbind().into_bound(py), position)))
+}
+
+
+#[pyfunction]
+#[pyo3(pass_module, signature = (substrate, asn1_spec, offset, *, utc_time_pivot_year = i64::from(time::DEFAULT_UTC_TIME_PIVOT_YEAR)))]
+fn frame_der_components<'py>(m: &Bound<'py, PyModule>, substrate: &Bound<'py, PyBytes>, asn1_spec: &Bound<'py, PyAny>, offset: usize, utc_time_pivot_year: i64) -> PyResult<(Bound<'py, PyAny>, Bound<'py, PyDict>)> {
+    let py = m.py();
+
+    let options = DecodeOptions { utc_time_pivot_year: Some(get_utc_time_pivot_year(utc_time_pivot_year)?), ..Default::default() };
+
+    let ctx = create_decode_context(m, substrate, false, options)?;
+
+    if offset >= substrate.as_bytes().len() {
+        return Err(Pyasn1FasderError::new_err(format!("No TLV at substrate offset {}", offset)));
+    }
+
+    let (header, _) = ctx.read_tlv(&substrate.as_bytes()[offset..], offset)?;
+
+    let position_cls = &ctx.module().position_cls;
+
+    // the positions of the components are keyed by their position in the specification or their index
+    let component_positions = PyDict::new(py);
+
+    for component in navigate::frame_components(&ctx, substrate.as_bytes(), asn1_spec, offset)? {
+        component_positions.set_item(component.key, position_cls.call1((component.offset, component.header.header_len, component.header.value_len))?)?;
+    }
+
+    let position = position_cls.call1((offset, header.header_len, header.value_len))?;
+
+    Ok((position.unbind().into_bound(py), component_positions))
+}
+
+
+#[pyfunction]
+#[pyo3(pass_module, signature = (substrate, asn1_spec, offset, *, utc_time_pivot_year = i64::from(time::DEFAULT_UTC_TIME_PIVOT_YEAR)))]
+fn decode_der_at<'py>(m: &Bound<'py, PyModule>, substrate: &Bound<'py, PyBytes>, asn1_spec: &Bound<'py, PyAny>, offset: usize, utc_time_pivot_year: i64) -> PyResult<Bound<'py, PyAny>> {
+    let py = m.py();
+
+    let options = DecodeOptions { utc_time_pivot_year: Some(get_utc_time_pivot_year(utc_time_pivot_year)?), ..Default::default() };
+
+    let ctx = create_decode_context(m, substrate, false, options)?;
+
+    if offset >= substrate.as_bytes().len() {
+        return Err(Pyasn1FasderError::new_err(format!("No TLV at substrate offset {}", offset)));
+    }
+
+    let (_, tlv) = ctx.read_tlv(&substrate.as_bytes()[offset..], offset)?;
+
+    let decoded = decode_der_rec(ctx, tlv, asn1_spec.clone(), None, offset, ComponentPath::root())?;
+
+    Ok(decoded.unbind().into_bound(py))
+}
+
+
+#[pyfunction]
+#[pyo3(pass_module, signature = (substrate, asn1_spec, *, octets_encoding = "hex"))]
+fn der_to_jer<'py>(m: &Bound<'py, PyModule>, substrate: &Bound<'py, PyAny>, asn1_spec: &Bound<'py, PyAny>, octets_encoding: &str) -> PyResult<String> {
+    let octets_encoding = match octets_encoding {
+        "hex" => OctetsEncoding::Hex,
+        "base64" => OctetsEncoding::Base64,
+        _ => return Err(PyValueError::new_err(format!("Unsupported octets encoding: {}", octets_encoding)))
+    };
+
+    let substrate_bytes = get_substrate_octets(substrate)?;
+
+    // the JER encoding is written while the substrate is decoded into plain Python objects, which are not kept
+    let ctx = create_decode_context(m, substrate, false, DecodeOptions { native_values: true, jer_octets_encoding: Some(octets_encoding), ..Default::default() })?;
+
+    decode_der_rec(ctx.clone(), substrate_bytes.as_bytes(), asn1_spec.clone(), None, 0, ComponentPath::root())?;
+
+    Ok(ctx.take_jer().unwrap())
+}
+
+
+#[pyfunction]
+#[pyo3(pass_module)]
+fn validate_der<'py>(m: &Bound<'py, PyModule>, substrate: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyList>> {
+    let py = m.py();
+
+    let substrate_bytes = get_substrate_octets(substrate)?;
+
+    let helper_module = m.getattr(intern![py, HELPER_MODULE_ATTR])?;
+    let finding_cls = helper_module.getattr(intern![py, "_FINDING_CLS"])?;
+
+    let findings = PyList::empty(py);
+
+    let substrate_octets = substrate_bytes.as_bytes();
+
+    for finding in py.allow_threads(|| validate::validate_der(substrate_octets)) {
+        findings.append(finding_cls.call1((finding.offset, finding.description))?)?;
+    }
+
+    Ok(findings)
+}
+
+
 #[pyfunction]
 #[pyo3(pass_module)]
-fn decode_der<'py>(m: &Bound<'py, PyModule>, substrate: &'py [u8], asn1_spec: &'py Bound<'py, PyAny>) -> PyResult<Bound<'py, PyAny>> {
-    let native_module = NativeHelperModule::new(m)?;
+fn canonicalize_der<'py>(m: &Bound<'py, PyModule>, substrate: &Bound<'py, PyAny>) -> PyResult<(Bound<'py, PyBytes>, Bound<'py, PyList>)> {
+    let py = m.py();
+
+    let substrate_bytes = get_substrate_octets(substrate)?;
+
+    let helper_module = m.getattr(intern![py, HELPER_MODULE_ATTR])?;
+    let finding_cls = helper_module.getattr(intern![py, "_FINDING_CLS"])?;
+
+    let substrate_octets = substrate_bytes.as_bytes();
 
-    decode_der_rec(native_module, substrate, asn1_spec.clone(), None, 0)
+    let (octets, changes) = py.allow_threads(|| canonicalize::canonicalize_der(substrate_octets))
+        .map_err(Pyasn1FasderError::new_err)?;
+
+    let changes_list = PyList::empty(py);
+
+    for change in changes {
+        changes_list.append(finding_cls.call1((change.offset, change.description))?)?;
+    }
+
+    Ok((PyBytes::new(py, &octets), changes_list))
+}
+
+
+#[pyfunction]
+#[pyo3(pass_module)]
+fn reset_helper_state(m: &Bound<PyModule>) -> PyResult<()> {
+    let helper_module = NativeHelperModule::load(m)?;
+
+    for cache in [&helper_module.tag_cache, &helper_module.tagset_cache, &helper_module.explicit_tagset_cache,
+                  &helper_module.oid_cache] {
+        cache.clear();
+    }
+
+    initialize_helper_state(m)
 }
 
 
 fn initialize_module(m: &Bound<PyModule>) -> PyResult<()> {
-    let helper_mod = m.py().import_bound("pyasn1_fasder._native_helper")?;
+    let helper_mod = m.py().import("pyasn1_fasder._native_helper")?;
 
     m.setattr(HELPER_MODULE_ATTR, helper_mod)?;
 
     tag::init_module(m)?;
 
-    decoder::init_module(m)
+    decoder::init_module(m)?;
+
+    initialize_helper_state(m)
 }
 
 
-#[pymodule]
+// the module does not rely on the GIL for thread safety, so it can be imported on free-threaded builds without
+// enabling the GIL. PyO3 refuses to initialize the module in more than one interpreter, as its interned strings and
+// type objects are process-wide; the state of the module itself is held in module attributes (see `HelperState`)
+#[pymodule(gil_used = false)]
 #[pyo3(name="_native")]
 fn pyasn1_fasder(_py: Python, m: &Bound<PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(decode_der, m)?)?;
+    m.add_function(wrap_pyfunction!(decode_ber, m)?)?;
+    m.add_function(wrap_pyfunction!(decode_cer, m)?)?;
+    m.add_function(wrap_pyfunction!(decode_der_native, m)?)?;
+    m.add_function(wrap_pyfunction!(decode_der_path, m)?)?;
+    m.add_function(wrap_pyfunction!(frame_der_components, m)?)?;
+    m.add_function(wrap_pyfunction!(decode_der_at, m)?)?;
+    m.add_function(wrap_pyfunction!(der_to_jer, m)?)?;
+    m.add_function(wrap_pyfunction!(validate_der, m)?)?;
+    m.add_function(wrap_pyfunction!(canonicalize_der, m)?)?;
+    m.add_function(wrap_pyfunction!(reset_helper_state, m)?)?;
 
     initialize_module(m)?;
 
@@ -0,0 +1,340 @@
+use crate::decoder::{self, DecodeContext, TlvHeader, DECODER_TYPE_CHOICE, DECODER_TYPE_SEQUENCE, DECODER_TYPE_SEQUENCEOF, DECODER_TYPE_SETOF};
+use crate::path::{ComponentPath, PathSegment};
+use crate::{decode_der_rec, Pyasn1FasderError, TAGMAP_ATTR, TAGSET_ATTR};
+use pyo3::prelude::{PyAnyMethods, PyDictMethods, PyStringMethods, PyTypeMethods};
+use pyo3::types::PySet;
+use pyo3::{intern, Bound, PyAny, PyResult};
+use std::rc::Rc;
+
+
+/// The TLV of a component that was located by its path, along with its ASN.1 specification.
+pub struct LocatedComponent<'a, 'py> {
+    pub tlv: &'a [u8],
+    pub asn1_spec: Bound<'py, PyAny>,
+    pub offset: usize
+}
+
+
+/// The outcome of locating a component by its path.
+pub enum Location<'a, 'py> {
+    Present(LocatedComponent<'a, 'py>),
+    /// A SEQUENCE component with a default value that is absent from the substrate, along with its default value.
+    Defaulted(Bound<'py, PyAny>),
+    Absent
+}
+
+
+/// Locates the TLV of the component at the specified path without decoding the components that precede it. The TLVs
+/// of the components that are skipped are only checked for correct framing. The component is absent if it is an
+/// optional component that is not present, an index beyond the end of a SEQUENCE OF or SET OF or an alternative of a
+/// CHOICE that was not chosen, or if it is nested in an absent component.
+pub fn locate_component<'a, 'py>(ctx: &Rc<DecodeContext<'py>>, substrate: &'a [u8], asn1_spec: &Bound<'py, PyAny>, segments: &[PathSegment<'py>]) -> PyResult<Location<'a, 'py>> {
+    let (_, tlv) = ctx.read_tlv(substrate, 0)?;
+
+    if substrate.len() != tlv.len() {
+        return Err(Pyasn1FasderError::new_err(format!("{} trailing octet(s) after TLV near substrate offset 0", substrate.len() - tlv.len())));
+    }
+
+    let mut component = LocatedComponent { tlv, asn1_spec: asn1_spec.clone(), offset: 0 };
+
+    for (i, segment) in segments.iter().enumerate() {
+        component = match locate_child(ctx, component, segment)? {
+            Location::Present(c) => c,
+            // the components of an absent value with a default value are not located within the default value
+            Location::Defaulted(default_value) if i == segments.len() - 1 => return Ok(Location::Defaulted(default_value)),
+            _ => return Ok(Location::Absent)
+        };
+    }
+
+    Ok(Location::Present(component))
+}
+
+
+fn create_error(component: &LocatedComponent, description: &str) -> pyo3::PyErr {
+    let asn1_spec_name = component.asn1_spec.get_type().name().map(|n| n.to_string()).unwrap_or_default();
+
+    Pyasn1FasderError::new_err(format!("Error locating component in \"{}\" TLV near substrate offset {}: {}", asn1_spec_name, component.offset, description))
+}
+
+
+/// Checks the tags of a component against its ASN.1 specification and descends into its explicit tags. Returns the
+/// TLV within the explicit tags, along with the decoder type of the specification.
+fn unwrap_explicit_tags<'a, 'py>(ctx: &Rc<DecodeContext<'py>>, component: LocatedComponent<'a, 'py>) -> PyResult<(LocatedComponent<'a, 'py>, usize)> {
+    let py = component.asn1_spec.py();
+    let m = ctx.module();
+
+    if decoder::find_tag_set_in_tag_map(m, &component.asn1_spec.getattr(intern![py, TAGMAP_ATTR])?, component.tlv)?.is_none() {
+        return Err(create_error(&component, "Substrate does not match ASN.1 specification"));
+    }
+
+    let type_id = component.asn1_spec.getattr(intern![py, "typeId"])?;
+
+    let decoder_id: usize = match m.decoder_mappings.get_item(&type_id)? {
+        Some(d) if !d.is_callable() => d.extract()?,
+        _ => return Err(create_error(&component, "Components of the ASN.1 specification cannot be located"))
+    };
+
+    // the tag set of an untagged CHOICE is empty, as the CHOICE spans the TLV of the chosen alternative
+    let untagged_len = if decoder_id == DECODER_TYPE_CHOICE { 0 } else { 1 };
+    let explicit_tag_count = component.asn1_spec.getattr(intern![py, TAGSET_ATTR])?.len()? - untagged_len;
+
+    let mut tlv = component.tlv;
+    let mut offset = component.offset;
+
+    for _ in 0..explicit_tag_count {
+        let (header, _) = ctx.read_tlv(tlv, offset)?;
+
+        let value = &tlv[header.header_len..header.header_len + header.value_len];
+        let (_, inner_tlv) = ctx.read_tlv(value, offset + header.header_len)?;
+
+        if value.len() != inner_tlv.len() {
+            return Err(create_error(&component, "Trailing octets after explicitly tagged TLV"));
+        }
+
+        tlv = inner_tlv;
+        offset += header.header_len;
+    }
+
+    Ok((LocatedComponent { tlv, asn1_spec: component.asn1_spec, offset }, decoder_id))
+}
+
+
+fn locate_child<'a, 'py>(ctx: &Rc<DecodeContext<'py>>, component: LocatedComponent<'a, 'py>, segment: &PathSegment<'py>) -> PyResult<Location<'a, 'py>> {
+    let (component, decoder_id) = unwrap_explicit_tags(ctx, component)?;
+
+    match (decoder_id, segment) {
+        (DECODER_TYPE_SEQUENCE, PathSegment::Name(name)) => locate_sequence_component(ctx, component, name.to_cow()?.as_ref()),
+        (DECODER_TYPE_SEQUENCEOF | DECODER_TYPE_SETOF, PathSegment::Index(index)) => locate_sequence_of_component(ctx, component, *index),
+        (DECODER_TYPE_CHOICE, PathSegment::Name(name)) => locate_choice_alternative(ctx, component, name.to_cow()?.as_ref()),
+        (DECODER_TYPE_SEQUENCE | DECODER_TYPE_CHOICE, PathSegment::Index(_)) => Err(create_error(&component, "Components must be identified by name")),
+        (DECODER_TYPE_SEQUENCEOF | DECODER_TYPE_SETOF, PathSegment::Name(_)) => Err(create_error(&component, "Components must be identified by index")),
+        _ => Err(create_error(&component, "ASN.1 specification does not have components"))
+    }
+}
+
+
+/// Returns the TLVs of the components of a constructed value along with their offsets and headers.
+fn read_components<'a>(ctx: &DecodeContext, component: &LocatedComponent<'a, '_>) -> PyResult<Vec<(&'a [u8], usize, TlvHeader)>> {
+    let (header, _) = ctx.read_tlv(component.tlv, component.offset)?;
+
+    let value = &component.tlv[header.header_len..header.header_len + header.value_len];
+    let value_offset = component.offset + header.header_len;
+
+    let mut components = Vec::new();
+    let mut relative_offset = 0;
+
+    while relative_offset < value.len() {
+        let (component_header, tlv) = ctx.read_tlv(&value[relative_offset..], value_offset + relative_offset)?;
+
+        components.push((tlv, value_offset + relative_offset, component_header));
+
+        relative_offset += tlv.len();
+    }
+
+    Ok(components)
+}
+
+
+/// A component of a SEQUENCE along with its position within the specification.
+struct ResolvedComponent<'a> {
+    index: usize,
+    tlv: &'a [u8],
+    offset: usize,
+    header: TlvHeader
+}
+
+
+/// Determines the positions of the components of a SEQUENCE within its specification, stopping at the first component
+/// whose position is at or beyond the specified position.
+fn resolve_sequence_components<'a, 'py>(ctx: &Rc<DecodeContext<'py>>, component: &LocatedComponent<'a, 'py>, named_types: &Bound<'py, PyAny>, last_index: Option<usize>) -> PyResult<Vec<ResolvedComponent<'a>>> {
+    let py = named_types.py();
+
+    let mut resolved_components = Vec::new();
+    let mut index = 0;
+
+    for (tlv, offset, header) in read_components(ctx, component)? {
+        let named_type = match named_types.get_item(index) {
+            Ok(n) => n,
+            Err(_) => return Err(create_error(component, "Excessive components detected"))
+        };
+
+        let is_optional_or_defaulted = named_type.getattr(intern![py, "isOptional"])?.is_truthy()? || named_type.getattr(intern![py, "isDefaulted"])?.is_truthy()?;
+
+        // the position of an optional or defaulted component is determined from its tags as the decoder does
+        if is_optional_or_defaulted {
+            let tag_map = named_types.call_method1(intern![py, "getTagMapNearPosition"], (index,))?;
+
+            let resolved_index = match decoder::find_tag_set_in_tag_map(ctx.module(), &tag_map, tlv)? {
+                Some(tag_set) => named_types.call_method1(intern![py, "getPositionNearType"], (tag_set, index)).ok(),
+                None => None
+            };
+
+            index = match resolved_index {
+                Some(i) => i.extract()?,
+                None => return Err(create_error(component, "Unable to determine component position"))
+            };
+        }
+
+        resolved_components.push(ResolvedComponent { index, tlv, offset, header });
+
+        if last_index.is_some_and(|l| index >= l) {
+            break;
+        }
+
+        index += 1;
+    }
+
+    Ok(resolved_components)
+}
+
+
+fn locate_sequence_component<'a, 'py>(ctx: &Rc<DecodeContext<'py>>, component: LocatedComponent<'a, 'py>, name: &str) -> PyResult<Location<'a, 'py>> {
+    let py = component.asn1_spec.py();
+
+    let named_types = component.asn1_spec.getattr(intern![py, "componentType"])?;
+
+    let target_index: usize = match named_types.call_method1(intern![py, "getPositionByName"], (name,)) {
+        Ok(i) => i.extract()?,
+        Err(_) => return Err(create_error(&component, &format!("Unknown component \"{}\"", name)))
+    };
+
+    // components are encoded in the order of the specification, so only the last resolved component can match
+    match resolve_sequence_components(ctx, &component, &named_types, Some(target_index))?.pop() {
+        Some(resolved) if resolved.index == target_index => {
+            let asn1_spec = named_types.call_method1(intern![py, "getTypeByPosition"], (target_index,))?;
+
+            Ok(Location::Present(LocatedComponent { tlv: resolved.tlv, asn1_spec, offset: resolved.offset }))
+        },
+        _ => {
+            let named_type = named_types.get_item(target_index)?;
+
+            if named_type.getattr(intern![py, "isDefaulted"])?.is_truthy()? {
+                Ok(Location::Defaulted(named_type.getattr(intern![py, "asn1Object"])?))
+            } else {
+                Ok(Location::Absent)
+            }
+        }
+    }
+}
+
+
+fn locate_sequence_of_component<'a, 'py>(ctx: &Rc<DecodeContext<'py>>, component: LocatedComponent<'a, 'py>, index: usize) -> PyResult<Location<'a, 'py>> {
+    let py = component.asn1_spec.py();
+
+    let asn1_spec = component.asn1_spec.getattr(intern![py, "componentType"])?;
+
+    match read_components(ctx, &component)?.into_iter().nth(index) {
+        Some((tlv, offset, _)) => Ok(Location::Present(LocatedComponent { tlv, asn1_spec, offset })),
+        None => Ok(Location::Absent)
+    }
+}
+
+
+/// Returns the position of the chosen alternative of a CHOICE within its specification.
+fn resolve_choice_position<'py>(ctx: &Rc<DecodeContext<'py>>, component: &LocatedComponent<'_, 'py>, named_types: &Bound<'py, PyAny>) -> PyResult<usize> {
+    let py = named_types.py();
+
+    let component_tag_map = component.asn1_spec.getattr(intern![py, "componentTagMap"])?;
+
+    let tag_set = match decoder::find_tag_set_in_tag_map(ctx.module(), &component_tag_map, component.tlv)? {
+        Some(t) => t,
+        None => return Err(create_error(component, "Substrate does not match ASN.1 specification"))
+    };
+
+    named_types.call_method1(intern![py, "getPositionByType"], (tag_set,))?.extract()
+}
+
+
+fn locate_choice_alternative<'a, 'py>(ctx: &Rc<DecodeContext<'py>>, component: LocatedComponent<'a, 'py>, name: &str) -> PyResult<Location<'a, 'py>> {
+    let py = component.asn1_spec.py();
+
+    let named_types = component.asn1_spec.getattr(intern![py, "componentType"])?;
+
+    let target_index: usize = match named_types.call_method1(intern![py, "getPositionByName"], (name,)) {
+        Ok(i) => i.extract()?,
+        Err(_) => return Err(create_error(&component, &format!("Unknown component \"{}\"", name)))
+    };
+
+    if resolve_choice_position(ctx, &component, &named_types)? != target_index {
+        return Ok(Location::Absent);
+    }
+
+    // the chosen alternative spans the TLV of the CHOICE, excluding any explicit tags of the CHOICE
+    let asn1_spec = named_types.call_method1(intern![py, "getTypeByPosition"], (target_index,))?;
+
+    Ok(Location::Present(LocatedComponent { tlv: component.tlv, asn1_spec, offset: component.offset }))
+}
+
+
+/// The TLV of a component of a constructed value. The key is the position of the component within the specification
+/// for SEQUENCEs and CHOICEs, and the index of the component for SEQUENCE OFs and SET OFs.
+pub struct FramedComponent {
+    pub key: usize,
+    pub offset: usize,
+    pub header: TlvHeader
+}
+
+
+/// Reads the TLVs of the components of the constructed value at the specified offset without decoding them. The rules
+/// that concern the value as a whole are checked: the presence of required SEQUENCE components, the omission of
+/// default values and the order of SET OF components.
+pub fn frame_components<'py>(ctx: &Rc<DecodeContext<'py>>, substrate: &'py [u8], asn1_spec: &Bound<'py, PyAny>, offset: usize) -> PyResult<Vec<FramedComponent>> {
+    let py = asn1_spec.py();
+
+    let (_, tlv) = ctx.read_tlv(&substrate[offset..], offset)?;
+
+    let (component, decoder_id) = unwrap_explicit_tags(ctx, LocatedComponent { tlv, asn1_spec: asn1_spec.clone(), offset })?;
+
+    match decoder_id {
+        DECODER_TYPE_SEQUENCE => {
+            let named_types = component.asn1_spec.getattr(intern![py, "componentType"])?;
+
+            let resolved_components = resolve_sequence_components(ctx, &component, &named_types, None)?;
+
+            let seen_indices = PySet::new(py, resolved_components.iter().map(|c| c.index).collect::<Vec<_>>().iter())?;
+
+            if !named_types.getattr(intern![py, "requiredComponents"])?.call_method1(intern![py, "issubset"], (seen_indices,))?.is_truthy()? {
+                return Err(create_error(&component, "Missing required components"));
+            }
+
+            let mut framed_components = Vec::with_capacity(resolved_components.len());
+
+            for resolved in resolved_components {
+                let named_type = named_types.get_item(resolved.index)?;
+
+                // default values are decoded so that their omission can be checked without building the other components
+                if named_type.getattr(intern![py, "isDefaulted"])?.is_truthy()? {
+                    let decoded = decode_der_rec(ctx.clone(), resolved.tlv, named_type.getattr(intern![py, "asn1Object"])?, None, resolved.offset, ComponentPath::root())?;
+
+                    if decoded.eq(named_type.getattr(intern![py, "asn1Object"])?)? {
+                        return Err(create_error(&component, "Explicitly encoded default value"));
+                    }
+                }
+
+                framed_components.push(FramedComponent { key: resolved.index, offset: resolved.offset, header: resolved.header });
+            }
+
+            Ok(framed_components)
+        },
+        DECODER_TYPE_SEQUENCEOF | DECODER_TYPE_SETOF => {
+            let components = read_components(ctx, &component)?;
+
+            if decoder_id == DECODER_TYPE_SETOF {
+                if let Some(index) = components.windows(2).position(|w| w[0].0 > w[1].0) {
+                    return Err(create_error(&component, &format!("Out of order component at index {}", index + 1)));
+                }
+            }
+
+            Ok(components.into_iter().enumerate().map(|(index, (_, component_offset, header))| FramedComponent { key: index, offset: component_offset, header }).collect())
+        },
+        DECODER_TYPE_CHOICE => {
+            let named_types = component.asn1_spec.getattr(intern![py, "componentType"])?;
+
+            let position = resolve_choice_position(ctx, &component, &named_types)?;
+            let (header, _) = ctx.read_tlv(component.tlv, component.offset)?;
+
+            Ok(vec![FramedComponent { key: position, offset: component.offset, header }])
+        },
+        _ => Err(create_error(&component, "ASN.1 specification does not have components"))
+    }
+}
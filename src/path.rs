@@ -0,0 +1,65 @@
+use std::fmt::{Display, Formatter};
+use pyo3::types::{PyString, PyStringMethods, PyTuple};
+use pyo3::{Bound, IntoPyObject, PyAny, PyResult, Python};
+
+
+#[derive(Clone)]
+pub enum PathSegment<'py> {
+    Name(Bound<'py, PyString>),
+    Index(usize)
+}
+
+impl<'py> PathSegment<'py> {
+    fn to_py_any(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        match self {
+            PathSegment::Name(n) => Ok(n.clone().into_any()),
+            PathSegment::Index(i) => Ok(i.into_pyobject(py)?.into_any())
+        }
+    }
+}
+
+
+/// The location of a component within the decoded value. Components of SEQUENCEs and alternatives of CHOICEs are
+/// identified by name; components of SEQUENCE OFs and SET OFs are identified by their index.
+#[derive(Clone, Default)]
+pub struct ComponentPath<'py> {
+    segments: Vec<PathSegment<'py>>
+}
+
+impl<'py> ComponentPath<'py> {
+    pub fn root() -> Self {
+        Self { segments: Vec::new() }
+    }
+
+    pub fn child(&self, segment: PathSegment<'py>) -> Self {
+        let mut segments = self.segments.clone();
+        segments.push(segment);
+
+        Self { segments }
+    }
+
+    pub fn to_py_tuple(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+        PyTuple::new(py, self.segments.iter().map(|s| s.to_py_any(py)).collect::<PyResult<Vec<_>>>()?)
+    }
+}
+
+impl Display for ComponentPath<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.segments.is_empty() {
+            return write!(f, "<root>");
+        }
+
+        for (i, segment) in self.segments.iter().enumerate() {
+            if i > 0 {
+                write!(f, ".")?;
+            }
+
+            match segment {
+                PathSegment::Name(n) => write!(f, "{}", n.to_string_lossy())?,
+                PathSegment::Index(i) => write!(f, "{}", i)?
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -4,6 +4,9 @@ use pyo3::{Bound, PyResult};
 
 pub const CLASS_MASK: u8 = 0xC0;
 pub const CLASS_UNIVERSAL: u8 = 0x00;
+pub const CLASS_APPLICATION: u8 = 0x40;
+pub const CLASS_CONTEXT: u8 = 0x80;
+pub const CLASS_PRIVATE: u8 = 0xC0;
 
 pub const FORMAT_MASK: u8 = 0x20;
 pub const FORMAT_CONSTRUCTED: u8 = 0x20;
@@ -11,6 +14,11 @@ pub const FORMAT_SIMPLE: u8 = 0x00;
 
 const TAG_ID_MASK: u8 = 0x1F;
 
+pub const TAG_ID_BIT_STRING: u8 = 3;
+pub const TAG_ID_OCTET_STRING: u8 = 4;
+pub const TAG_ID_SEQUENCE: u8 = 16;
+pub const TAG_ID_SET: u8 = 17;
+
 const TAG_CLS: &str = "_TAG_CLS";
 const TAGSET_CLS: &str = "_TAGSET_CLS";
 const TAG_CACHE: &str = "_TAG_CACHE";
@@ -41,6 +49,41 @@ impl Asn1Tag {
     }
 }
 
+/// Returns the name of the type with the specified universal tag number, or None if the tag number is reserved.
+pub fn universal_type_name(tag_id: u8) -> Option<&'static str> {
+    match tag_id {
+        1 => Some("BOOLEAN"),
+        2 => Some("INTEGER"),
+        3 => Some("BIT STRING"),
+        4 => Some("OCTET STRING"),
+        5 => Some("NULL"),
+        6 => Some("OBJECT IDENTIFIER"),
+        7 => Some("ObjectDescriptor"),
+        8 => Some("EXTERNAL"),
+        9 => Some("REAL"),
+        10 => Some("ENUMERATED"),
+        11 => Some("EMBEDDED PDV"),
+        12 => Some("UTF8String"),
+        13 => Some("RELATIVE-OID"),
+        16 => Some("SEQUENCE"),
+        17 => Some("SET"),
+        18 => Some("NumericString"),
+        19 => Some("PrintableString"),
+        20 => Some("TeletexString"),
+        21 => Some("VideotexString"),
+        22 => Some("IA5String"),
+        23 => Some("UTCTime"),
+        24 => Some("GeneralizedTime"),
+        25 => Some("GraphicString"),
+        26 => Some("VisibleString"),
+        27 => Some("GeneralString"),
+        28 => Some("UniversalString"),
+        29 => Some("CHARACTER STRING"),
+        30 => Some("BMPString"),
+        _ => None
+    }
+}
+
 impl From<Asn1Tag> for u8 {
     fn from(value: Asn1Tag) -> Self {
         value.raw
@@ -56,13 +99,13 @@ impl From<u8> for Asn1Tag {
 pub fn init_module(m: &Bound<PyModule>) -> PyResult<()> {
     let py = m.py();
 
-    let pyasn1_tag_mod = py.import_bound("pyasn1.type.tag")?;
+    let pyasn1_tag_mod = py.import("pyasn1.type.tag")?;
 
     m.setattr(TAG_CLS, pyasn1_tag_mod.getattr("Tag")?)?;
     m.setattr(TAGSET_CLS, pyasn1_tag_mod.getattr("TagSet")?)?;
-    m.setattr(TAGMAP_CLS, py.import_bound("pyasn1.type.tagmap")?.getattr("TagMap")?)?;
+    m.setattr(TAGMAP_CLS, py.import("pyasn1.type.tagmap")?.getattr("TagMap")?)?;
 
-    let helper_mod = py.import_bound("pyasn1_fasder._native_helper")?;
+    let helper_mod = py.import("pyasn1_fasder._native_helper")?;
 
     m.setattr(TAG_CACHE, helper_mod.getattr(TAG_CACHE)?)?;
     m.setattr(TAGSET_CACHE, helper_mod.getattr(TAGSET_CACHE)?)
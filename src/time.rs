@@ -0,0 +1,169 @@
+/// A UTC timestamp parsed from the value of a UTCTime or GeneralizedTime.
+pub struct Timestamp {
+    pub year: u16,
+    pub month: u8,
+    pub day: u8,
+    pub hour: u8,
+    pub minute: u8,
+    pub second: u8,
+    pub microsecond: u32
+}
+
+
+/// Years that a UTCTime two-digit year is mapped onto by default, as specified in RFC 5280
+pub const DEFAULT_UTC_TIME_PIVOT_YEAR: u16 = 1950;
+
+
+fn parse_digits(value: &[u8]) -> Option<u32> {
+    value.iter().try_fold(0, |acc, c| {
+        if c.is_ascii_digit() {
+            Some(acc * 10 + u32::from(c - b'0'))
+        }
+        else {
+            None
+        }
+    })
+}
+
+
+fn is_leap_year(year: u16) -> bool {
+    (year.is_multiple_of(4) && !year.is_multiple_of(100)) || year.is_multiple_of(400)
+}
+
+
+fn days_in_month(year: u16, month: u8) -> u8 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31
+    }
+}
+
+
+/// Parses the date and time fields that follow the year, in the form MMDDHHMMSS.
+fn parse_date_time(year: u16, value: &[u8], type_name: &str) -> Result<Timestamp, String> {
+    let field = |i: usize| parse_digits(&value[i..i + 2]).ok_or_else(|| format!("Invalid {} value", type_name));
+
+    let (month, day, hour, minute, second) = (field(0)? as u8, field(2)? as u8, field(4)? as u8, field(6)? as u8, field(8)? as u8);
+
+    if !(1..=12).contains(&month) || day < 1 || day > days_in_month(year, month) || hour > 23 || minute > 59 || second > 59 {
+        return Err(format!("Invalid date or time in {} value", type_name));
+    }
+
+    Ok(Timestamp { year, month, day, hour, minute, second, microsecond: 0 })
+}
+
+
+/// Parses the value of a UTCTime in the DER form YYMMDDHHMMSSZ. Two-digit years are mapped onto the century that
+/// starts with the specified pivot year.
+pub fn parse_utc_time(value: &[u8], pivot_year: u16) -> Result<Timestamp, String> {
+    if value.len() != 13 || value[12] != b'Z' {
+        return Err("Invalid UTCTIME value".to_string());
+    }
+
+    let two_digit_year = parse_digits(&value[..2]).ok_or("Invalid UTCTIME value")? as u16;
+
+    let pivot_century_year = pivot_year % 100;
+    let century = if two_digit_year >= pivot_century_year { pivot_year - pivot_century_year } else { pivot_year - pivot_century_year + 100 };
+
+    parse_date_time(century + two_digit_year, &value[2..12], "UTCTIME")
+}
+
+
+/// Parses the value of a GeneralizedTime in the DER form YYYYMMDDHHMMSS[.f]Z, where the fractional seconds must not
+/// have trailing zeros. Fractional seconds beyond microsecond precision are truncated.
+pub fn parse_generalized_time(value: &[u8]) -> Result<Timestamp, String> {
+    if value.len() < 15 || value[value.len() - 1] != b'Z' {
+        return Err("Invalid GENERALIZEDTIME value".to_string());
+    }
+
+    let year = parse_digits(&value[..4]).ok_or("Invalid GENERALIZEDTIME value")? as u16;
+
+    // year 0 cannot be represented as a datetime object
+    if year == 0 {
+        return Err("Invalid date or time in GENERALIZEDTIME value".to_string());
+    }
+
+    let mut timestamp = parse_date_time(year, &value[4..14], "GENERALIZEDTIME")?;
+
+    let fraction = &value[14..value.len() - 1];
+
+    if !fraction.is_empty() {
+        if fraction[0] != b'.' || fraction.len() == 1 || fraction[fraction.len() - 1] == b'0' {
+            return Err("Invalid fractional seconds in GENERALIZEDTIME value".to_string());
+        }
+
+        let digits = &fraction[1..];
+
+        if !digits.iter().all(u8::is_ascii_digit) {
+            return Err("Invalid fractional seconds in GENERALIZEDTIME value".to_string());
+        }
+
+        let micro_digits = &digits[..digits.len().min(6)];
+
+        timestamp.microsecond = parse_digits(micro_digits).unwrap() * 10u32.pow(6 - micro_digits.len() as u32);
+    }
+
+    Ok(timestamp)
+}
+
+
+/// Determines whether the value of a UTCTime or GeneralizedTime is in one of the forms that BER permits, which
+/// include times without seconds, local time differentials and, for GeneralizedTime, fractions of hours and minutes
+/// and local times.
+pub fn is_ber_time(value: &[u8], is_generalized: bool) -> bool {
+    let year_len = if is_generalized { 4 } else { 2 };
+    let digit_count = value.iter().take_while(|c| c.is_ascii_digit()).count();
+
+    let allowed_digit_counts: &[usize] = if is_generalized { &[10, 12, 14] } else { &[10, 12] };
+
+    if !allowed_digit_counts.contains(&digit_count) {
+        return false;
+    }
+
+    let year = match parse_digits(&value[..year_len]) {
+        // the century of a UTCTime value only affects leap years, for which any century is accepted
+        Some(y) if !is_generalized => 2000 + y as u16,
+        Some(y) if y > 0 => y as u16,
+        _ => return false
+    };
+
+    // omitted minutes and seconds are zero
+    let mut date_time = [b'0'; 10];
+    date_time[..digit_count - year_len].copy_from_slice(&value[year_len..digit_count]);
+
+    if parse_date_time(year, &date_time, "").is_err() {
+        return false;
+    }
+
+    let mut rest = &value[digit_count..];
+
+    if is_generalized && (rest.starts_with(b".") || rest.starts_with(b",")) {
+        let fraction_len = rest[1..].iter().take_while(|c| c.is_ascii_digit()).count();
+
+        if fraction_len == 0 {
+            return false;
+        }
+
+        rest = &rest[1 + fraction_len..];
+    }
+
+    match rest {
+        b"Z" => true,
+        // GeneralizedTime values without a suffix are local times
+        [] => is_generalized,
+        [b'+' | b'-', differential @ ..] if differential.len() == 4 || (is_generalized && differential.len() == 2) => {
+            match parse_digits(differential) {
+                Some(_) => {
+                    let hours = parse_digits(&differential[..2]).unwrap();
+                    let minutes = if differential.len() == 4 { parse_digits(&differential[2..]).unwrap() } else { 0 };
+
+                    hours <= 23 && minutes <= 59
+                },
+                None => false
+            }
+        },
+        _ => false
+    }
+}
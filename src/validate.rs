@@ -0,0 +1,106 @@
+use der::Encode;
+use crate::asn1_type::check_universal_tlv;
+use crate::decoder::parse_tlv;
+use crate::tag::{self, Asn1Tag};
+
+
+/// A DER encoding error detected by schema-independent validation.
+pub struct Finding {
+    pub offset: usize,
+    pub description: String
+}
+
+
+/// Returns the index of the first component of a SET value whose encoding sorts before that of the preceding
+/// component, or None if the components are in DER order.
+pub fn find_out_of_order_set_component(value: &[u8]) -> Option<usize> {
+    let mut relative_offset = 0;
+    let mut last_tlv: Option<&[u8]> = None;
+    let mut index = 0;
+
+    while relative_offset < value.len() {
+        let tlv = match parse_tlv(&value[relative_offset..]) {
+            Ok((_, tlv)) => tlv,
+            Err(_) => return None
+        };
+
+        if last_tlv.is_some_and(|l| l > tlv) {
+            return Some(index);
+        }
+
+        last_tlv = Some(tlv);
+        relative_offset += tlv.len();
+        index += 1;
+    }
+
+    None
+}
+
+
+/// Walks the TLV tree of the substrate and checks every TLV with a universal tag against the DER rules for the type
+/// denoted by its tag. The substrate must consist of exactly one TLV.
+pub fn validate_der(substrate: &[u8]) -> Vec<Finding> {
+    let mut findings = Vec::new();
+
+    match parse_tlv(substrate) {
+        Err(e) => findings.push(Finding { offset: 0, description: e.describe(0) }),
+        Ok((_, tlv)) => {
+            validate_tlvs(tlv, 0, &mut findings);
+
+            if substrate.len() != tlv.len() {
+                findings.push(Finding {
+                    offset: tlv.len(),
+                    description: format!("{} trailing octet(s) after TLV near substrate offset 0", substrate.len() - tlv.len())
+                });
+            }
+        }
+    }
+
+    findings
+}
+
+
+/// Checks the TLVs in the substrate and in the values of constructed TLVs in the order in which they occur. The values
+/// are walked with an explicit stack of partially checked values, as the nesting depth is not bounded.
+fn validate_tlvs(substrate: &[u8], base_offset: usize, findings: &mut Vec<Finding>) {
+    let mut pending_values = vec![(substrate, base_offset, 0)];
+
+    while let Some((value, value_offset, relative_offset)) = pending_values.pop() {
+        if relative_offset >= value.len() {
+            continue;
+        }
+
+        let offset = value_offset + relative_offset;
+
+        let (header, tlv) = match parse_tlv(&value[relative_offset..]) {
+            Ok(header_and_tlv) => header_and_tlv,
+            Err(e) => {
+                findings.push(Finding { offset, description: e.describe(offset) });
+
+                continue;
+            }
+        };
+
+        let tag = Asn1Tag::new(tlv[0]);
+        let header_len = usize::try_from(header.encoded_len().unwrap()).unwrap();
+
+        if tag.class() == tag::CLASS_UNIVERSAL {
+            if let Err(e) = check_universal_tlv(tag, &tlv[header_len..]) {
+                findings.push(Finding { offset, description: e });
+            }
+
+            if tag.tag_id() == tag::TAG_ID_SET && tag.format() == tag::FORMAT_CONSTRUCTED {
+                if let Some(index) = find_out_of_order_set_component(&tlv[header_len..]) {
+                    findings.push(Finding { offset, description: format!("Out of order SET component at index {}", index) });
+                }
+            }
+        }
+
+        // the remaining TLVs of the value are checked after those nested in this TLV
+        pending_values.push((value, value_offset, relative_offset + tlv.len()));
+
+        if tag.format() == tag::FORMAT_CONSTRUCTED {
+            pending_values.push((&tlv[header_len..], offset + header_len, 0));
+        }
+    }
+}
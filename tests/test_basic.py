@@ -1,9 +1,9 @@
-from pyasn1.type import univ
+from pyasn1.type import tag, univ
 from pyasn1.error import PyAsn1Error
 
 import pytest
 
-from pyasn1_fasder import decode_der
+from pyasn1_fasder import _native_helper, decode_der, reset_native_state
 
 
 def test_none_substrate():
@@ -45,3 +45,20 @@ def test_tag_mismatch():
 
     with pytest.raises(PyAsn1Error):
         decoded, _ = decode_der(octet_string, asn1Spec=univ.BitString())
+
+
+def test_reset_native_state():
+    spec = univ.ObjectIdentifier().subtype(explicitTag=tag.Tag(tag.tagClassContext, tag.tagFormatConstructed, 0))
+    substrate = bytes.fromhex('a0050603550403')
+
+    decoded, _ = decode_der(substrate, spec)
+
+    assert _native_helper._OID_CACHE and _native_helper._EXPLICIT_TAGSET_CACHE
+
+    reset_native_state()
+
+    assert not _native_helper._OID_CACHE and not _native_helper._EXPLICIT_TAGSET_CACHE
+
+    assert decode_der(substrate, spec)[0] == decoded
+    assert _native_helper._OID_CACHE and _native_helper._EXPLICIT_TAGSET_CACHE
+
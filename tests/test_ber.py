@@ -0,0 +1,130 @@
+import pytest
+from pyasn1.error import PyAsn1Error
+from pyasn1.type import char, namedtype, namedval, univ
+
+from pyasn1_fasder import decode_ber, decode_der
+
+
+class Record(univ.Sequence):
+    componentType = namedtype.NamedTypes(
+        namedtype.NamedType('octets', univ.OctetString()),
+        namedtype.NamedType('bits', univ.BitString()),
+        namedtype.NamedType('text', char.UTF8String()),
+        namedtype.DefaultedNamedType('version', univ.Integer(5)),
+    )
+
+
+RECORD_DER = bytes.fromhex('30140406616263646566030304aaa00c0568656c6c6f')
+
+
+def test_der_has_no_deviations():
+    decoded, deviations = decode_ber(RECORD_DER, Record())
+
+    assert decoded == decode_der(RECORD_DER, Record())[0]
+    assert deviations == []
+
+
+def test_indefinite_length_and_constructed_strings():
+    substrate = bytes.fromhex('30802480040461626364040265660000030304aaa02c80040468656c6c04016f00000000')
+
+    decoded, deviations = decode_ber(substrate, Record())
+
+    assert decoded == decode_der(RECORD_DER, Record())[0]
+    assert [(d.offset, d.description) for d in deviations] == [
+        (0, 'Indefinite length encoding'),
+        (2, 'Indefinite length encoding'),
+        (2, 'Invalid OCTET STRING value format'),
+        (21, 'Indefinite length encoding'),
+        (21, 'Invalid UTF8STRING value format'),
+    ]
+
+
+def test_constructed_octet_string_is_rejected_by_der():
+    substrate = bytes.fromhex('240804026162040263640000')
+
+    with pytest.raises(PyAsn1Error):
+        decode_der(substrate, univ.OctetString())
+
+
+def test_constructed_bit_string():
+    substrate = bytes.fromhex('230a0302000f03040480a0b0')
+
+    decoded, deviations = decode_ber(substrate, univ.BitString())
+
+    assert decoded == decode_der(bytes.fromhex('0305040f80a0b0'), univ.BitString())[0]
+    assert [d.description for d in deviations] == ['Invalid BIT STRING value format']
+
+
+def test_constructed_bit_string_with_trailer_in_inner_segment():
+    substrate = bytes.fromhex('230a0302040f03040480a0b0')
+
+    with pytest.raises(PyAsn1Error):
+        decode_ber(substrate, univ.BitString())
+
+
+def test_non_minimal_length():
+    decoded, deviations = decode_ber(bytes.fromhex('0281010a'), univ.Integer())
+
+    assert decoded == 10
+    assert [(d.offset, d.description) for d in deviations] == [(0, 'Non-minimal length encoding')]
+
+
+@pytest.mark.parametrize('substrate_hex,asn1_spec,description', [
+    ('010101', univ.Boolean(), 'Non-canonical BOOLEAN encoding'),
+    ('0302010F', univ.BitString(), 'Non-zero trailer value in BIT STRING'),
+    ('3106020102020101', univ.SetOf(univ.Integer()), 'Out of order component at index 1'),
+    ('3003020105', Record(), 'Missing required components'),
+])
+def test_der_rules(substrate_hex, asn1_spec, description):
+    substrate = bytes.fromhex(substrate_hex)
+
+    with pytest.raises(PyAsn1Error):
+        decode_der(substrate, asn1_spec)
+
+    if description == 'Missing required components':
+        # errors that are not DER-specific are raised in BER mode as well
+        with pytest.raises(PyAsn1Error):
+            decode_ber(substrate, asn1_spec)
+    else:
+        _, deviations = decode_ber(substrate, asn1_spec)
+
+        assert [d.description for d in deviations] == [description]
+
+
+def test_explicitly_encoded_default_value():
+    substrate = bytes.fromhex('30170406616263646566030304aaa00c0568656c6c6f020105')
+
+    decoded, deviations = decode_ber(substrate, Record())
+
+    assert decoded['version'] == 5
+    assert [(d.offset, d.description) for d in deviations] == [(0, 'Explicitly encoded default value')]
+
+
+@pytest.mark.parametrize('substrate_hex', [
+    '3080020105',
+    '04800000',
+    '0284000000',
+    '30FF',
+])
+def test_malformed(substrate_hex):
+    with pytest.raises(PyAsn1Error):
+        decode_ber(bytes.fromhex(substrate_hex), univ.Any())
+
+
+def test_positions_include_end_of_contents():
+    positions = {}
+
+    decode_ber(bytes.fromhex('30800201050000'), univ.SequenceOf(univ.Integer()), positions=positions)
+
+    assert positions[()].tlv_range == slice(0, 7)
+    assert positions[(0,)].offset == 2
+
+
+def test_named_bit_string_trailing_zero_bits():
+    class KeyUsage(univ.BitString):
+        namedValues = namedval.NamedValues(('digitalSignature', 0), ('nonRepudiation', 1), ('keyEncipherment', 2))
+
+    decoded, deviations = decode_ber(bytes.fromhex('0303008000'), KeyUsage())
+
+    assert decoded == decode_der(bytes.fromhex('03020780'), KeyUsage())[0]
+    assert [d.description for d in deviations] == ['Trailing zero bit in named BIT STRING']
@@ -0,0 +1,171 @@
+import array
+import mmap
+import tracemalloc
+
+from pyasn1.type import univ, namedtype, tag
+
+from pyasn1_fasder import decode_der
+
+
+class BufferSequence(univ.Sequence):
+    pass
+
+
+BufferSequence.componentType = namedtype.NamedTypes(
+    namedtype.NamedType('octets', univ.OctetString()),
+    namedtype.NamedType('any', univ.Any()),
+)
+
+
+class ExplicitBufferSequence(univ.Sequence):
+    pass
+
+
+ExplicitBufferSequence.componentType = namedtype.NamedTypes(
+    namedtype.NamedType('octets', univ.OctetString().subtype(
+        explicitTag=tag.Tag(tag.tagClassContext, tag.tagFormatSimple, 0))),
+)
+
+
+_SUBSTRATE = b'\x30\x08\x04\x03\x61\x62\x63\x02\x01\x05'
+
+
+def test_bytearray_substrate():
+    decoded, _ = decode_der(bytearray(_SUBSTRATE), asn1Spec=BufferSequence())
+
+    assert bytes(decoded['octets']) == b'abc'
+    assert bytes(decoded['any']) == b'\x02\x01\x05'
+
+
+def test_memoryview_substrate():
+    decoded, _ = decode_der(memoryview(_SUBSTRATE), asn1Spec=BufferSequence())
+
+    assert bytes(decoded['octets']) == b'abc'
+
+
+def test_memoryview_values():
+    decoded, _ = decode_der(_SUBSTRATE, asn1Spec=BufferSequence(), memoryviewValues=True)
+
+    assert isinstance(vars(decoded['octets'])['_value'], memoryview)
+    assert decoded['octets'] == b'abc'
+    assert decoded['any'].asOctets() == b'\x02\x01\x05'
+
+
+def test_memoryview_values_mmap():
+    with mmap.mmap(-1, len(_SUBSTRATE)) as m:
+        m.write(_SUBSTRATE)
+
+        decoded, _ = decode_der(m, asn1Spec=BufferSequence(), memoryviewValues=True)
+
+        assert vars(decoded['octets'])['_value'].obj is m
+        assert bytes(decoded['octets']) == b'abc'
+
+        del decoded
+
+
+def test_memoryview_values_explicit():
+    decoded, _ = decode_der(b'\x30\x07\xA0\x05\x04\x03\x61\x62\x63', asn1Spec=ExplicitBufferSequence(),
+                            memoryviewValues=True)
+
+    assert bytes(decoded['octets']) == b'abc'
+
+
+def test_memoryview_values_accessors():
+    decoded, _ = decode_der(bytearray(_SUBSTRATE), asn1Spec=BufferSequence(), memoryviewValues=True)
+
+    octets = decoded['octets']
+    expected = univ.OctetString(b'abc')
+
+    assert str(octets) == str(expected)
+    assert repr(octets) == repr(expected).replace('<OctetString ', '<OctetStringView ')
+    assert octets.prettyPrint() == expected.prettyPrint()
+    assert decoded['any'].prettyPrint() == univ.Any(b'\x02\x01\x05').prettyPrint()
+    assert octets + b'd' == b'abcd'
+    assert octets[1:] == b'bc'
+    assert octets < b'abd'
+    assert hash(octets) == hash(expected)
+    assert octets.clone(b'xyz') == b'xyz'
+    assert decoded.prettyPrint() == BufferSequence().setComponents(expected, b'\x02\x01\x05').prettyPrint()
+
+
+def test_memoryview_values_type():
+    decoded, _ = decode_der(_SUBSTRATE, asn1Spec=BufferSequence(), memoryviewValues=True)
+
+    octets = decoded['octets']
+
+    assert isinstance(octets, univ.OctetString)
+    assert type(octets) is not univ.OctetString
+    assert type(octets).__qualname__ == 'OctetStringView'
+    assert type(decoded['any']).__qualname__ == 'AnyView'
+
+
+def test_memoryview_values_clone():
+    decoded, _ = decode_der(_SUBSTRATE, asn1Spec=BufferSequence(), memoryviewValues=True)
+
+    octets = decoded['octets']
+
+    clone = octets.clone(octets)
+    assert type(clone) is type(octets)
+    assert clone == octets
+    assert clone.clone(bytes(clone)) == b'abc'
+
+    copy = univ.OctetString().clone(octets)
+    assert type(copy) is univ.OctetString
+    assert copy == b'abc'
+    assert isinstance(vars(copy)['_value'], bytes)
+
+
+def test_memoryview_values_not_copied():
+    value_len = 1 << 20
+
+    substrate = b'\x04\x83' + value_len.to_bytes(3, 'big') + bytes(value_len)
+
+    tracemalloc.start()
+
+    try:
+        decoded, _ = decode_der(substrate, asn1Spec=univ.OctetString(), memoryviewValues=True)
+
+        _, peak = tracemalloc.get_traced_memory()
+    finally:
+        tracemalloc.stop()
+
+    assert len(decoded) == value_len
+    assert peak < value_len // 4
+
+
+class WarningSequence(univ.Sequence):
+    pass
+
+
+WarningSequence.componentType = namedtype.NamedTypes(
+    namedtype.NamedType('numbers', univ.SequenceOf(univ.Integer())),
+    namedtype.NamedType('octets', univ.OctetString()),
+)
+
+
+def test_writable_substrate_is_copied():
+    substrate = bytearray(b'\x30\x07\x30\x00\x04\x03\x61\x62\x63')
+
+    # the callback is invoked for the empty SEQUENCE OF, which precedes the OCTET STRING
+    def overwrite_substrate(_):
+        substrate[-3:] = b'xyz'
+
+    decoded, _ = decode_der(substrate, asn1Spec=WarningSequence(), nonEmptyComponents=[('numbers',)],
+                            warningCallback=overwrite_substrate)
+
+    assert substrate[-3:] == b'xyz'
+    assert decoded['octets'] == b'abc'
+
+
+def test_non_contiguous_substrate():
+    decoded, _ = decode_der(memoryview(b'\x04\xFF\x02\xFF\x61\xFF\x62\xFF')[::2], asn1Spec=univ.OctetString())
+
+    assert decoded == b'ab'
+
+
+def test_non_octet_buffer_substrate():
+    substrate = array.array('H', b'\x04\x02\x61\x62')
+
+    decoded, _ = decode_der(substrate, asn1Spec=univ.OctetString())
+
+    assert decoded == b'ab'
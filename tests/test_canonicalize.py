@@ -0,0 +1,103 @@
+import pytest
+from pyasn1.error import PyAsn1Error
+from pyasn1.type import namedtype, univ
+
+from pyasn1_fasder import Pyasn1FasderError, canonicalize_der, decode_der
+
+
+class Record(univ.Sequence):
+    componentType = namedtype.NamedTypes(
+        namedtype.NamedType('octets', univ.OctetString()),
+        namedtype.DefaultedNamedType('version', univ.Integer(5)),
+    )
+
+
+@pytest.mark.parametrize('substrate_hex', [
+    '3003020105',
+    '3106020101020102',
+    '0C03E282AC',
+])
+def test_der_is_unchanged(substrate_hex):
+    substrate = bytes.fromhex(substrate_hex)
+
+    assert canonicalize_der(substrate) == (substrate, [])
+
+
+@pytest.mark.parametrize('substrate_hex,canonical_hex,description', [
+    ('0281010a', '02010a', 'Non-minimal length encoding'),
+    ('30800201050000', '3003020105', 'Indefinite length encoding'),
+    ('24080402616204026364', '040461626364', 'Invalid OCTET STRING value format'),
+    ('230a0302000f03040480a0b0', '0305040f80a0b0', 'Invalid BIT STRING value format'),
+    ('010101', '0101ff', 'Non-canonical BOOLEAN encoding'),
+    ('02030000ff', '020200ff', 'Non-minimal INTEGER encoding'),
+    ('0203ffff80', '020180', 'Non-minimal INTEGER encoding'),
+    ('0302010F', '0302010E', 'Non-zero trailer value in BIT STRING'),
+    ('3106020102020101', '3106020101020102', 'Out of order component at index 1'),
+])
+def test_schemaless_changes(substrate_hex, canonical_hex, description):
+    canonical, changes = canonicalize_der(bytes.fromhex(substrate_hex))
+
+    assert canonical == bytes.fromhex(canonical_hex)
+    assert description in [c.description for c in changes]
+
+
+def test_change_offsets():
+    canonical, changes = canonicalize_der(bytes.fromhex('3080a0800101010000028101050000'))
+
+    assert canonical == bytes.fromhex('3008a0030101ff020105')
+    assert [(c.offset, c.description) for c in changes] == [
+        (0, 'Indefinite length encoding'),
+        (2, 'Indefinite length encoding'),
+        (4, 'Non-canonical BOOLEAN encoding'),
+        (9, 'Non-minimal length encoding'),
+    ]
+
+
+def test_implicitly_tagged_string_is_unchanged_without_schema():
+    substrate = bytes.fromhex('a0080402616204026364')
+
+    assert canonicalize_der(substrate) == (substrate, [])
+
+
+def test_schema_drops_default_value():
+    substrate = bytes.fromhex('308024800402616200000201050000')
+
+    canonical, changes = canonicalize_der(substrate, Record())
+
+    assert canonical == bytes.fromhex('300404026162')
+    assert decode_der(canonical, Record())[0]['octets'] == b'ab'
+    assert 'Explicitly encoded default value' in [c.description for c in changes]
+
+
+@pytest.mark.parametrize('substrate_hex', [
+    '02010000',
+    '0200',
+    '01020000',
+    '0600',
+    '130126',
+    '3080020105',
+])
+def test_uncorrectable(substrate_hex):
+    with pytest.raises(PyAsn1Error):
+        canonicalize_der(bytes.fromhex(substrate_hex))
+
+
+def _nest(substrate, depth):
+    for _ in range(depth):
+        length = len(substrate).to_bytes(4, 'big').lstrip(b'\x00')
+        length_octets = length if len(substrate) < 0x80 else bytes([0x80 | len(length)]) + length
+
+        substrate = b'\x30' + length_octets + substrate
+
+    return substrate
+
+
+def test_nesting():
+    substrate = _nest(bytes.fromhex('0500'), 128)
+
+    assert canonicalize_der(substrate) == (substrate, [])
+
+
+def test_nesting_limit():
+    with pytest.raises(Pyasn1FasderError, match='Exceeded limit of 128 nested TLVs'):
+        canonicalize_der(_nest(bytes.fromhex('0500'), 200000))
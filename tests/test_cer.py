@@ -0,0 +1,95 @@
+import pytest
+from pyasn1.error import PyAsn1Error
+from pyasn1.type import char, univ
+
+from pyasn1_fasder import decode_cer
+
+
+def _primitive(tag, value):
+    length_octets = bytes([len(value)]) if len(value) < 0x80 else bytes([0x82]) + len(value).to_bytes(2, 'big')
+
+    return bytes([tag]) + length_octets + value
+
+
+def _segmented(tag, segments):
+    return bytes([tag, 0x80]) + b''.join(_primitive(0x04, s) for s in segments) + b'\x00\x00'
+
+
+def test_indefinite_length_sequence_of():
+    decoded, rest = decode_cer(bytes.fromhex('30800201050201010000'), univ.SequenceOf(univ.Integer()))
+
+    assert list(decoded) == [5, 1]
+    assert rest == b''
+
+
+def test_definite_length_constructed_is_rejected():
+    with pytest.raises(PyAsn1Error):
+        decode_cer(bytes.fromhex('3006020105020101'), univ.SequenceOf(univ.Integer()))
+
+
+@pytest.mark.parametrize('substrate_hex', [
+    '04810141',
+    '0482000141',
+    '2480048101410000',
+])
+def test_non_minimal_primitive_length_is_rejected(substrate_hex):
+    with pytest.raises(PyAsn1Error, match='Non-minimal length in primitive CER encoding'):
+        decode_cer(bytes.fromhex(substrate_hex), univ.OctetString())
+
+
+def test_short_primitive_string():
+    decoded, _ = decode_cer(bytes.fromhex('0403616263'), univ.OctetString())
+
+    assert decoded == b'abc'
+
+
+def test_segmented_octet_string():
+    substrate = _segmented(0x24, [b'a' * 1000, b'a' * 500])
+
+    decoded, _ = decode_cer(substrate, univ.OctetString())
+
+    assert decoded == b'a' * 1500
+
+
+def test_segmented_character_string():
+    substrate = _segmented(0x36, [b'a' * 1000, b'a'])
+
+    decoded, _ = decode_cer(substrate, char.IA5String())
+
+    assert str(decoded) == 'a' * 1001
+
+
+def test_segmented_bit_string():
+    substrate = b'\x23\x80' + _primitive(0x03, b'\x00' + b'\xff' * 999) + _primitive(0x03, b'\x00\xff') + b'\x00\x00'
+
+    decoded, _ = decode_cer(substrate, univ.BitString())
+
+    assert decoded.asOctets() == b'\xff' * 1000
+
+
+@pytest.mark.parametrize('substrate', [
+    # long primitive string
+    _primitive(0x04, b'a' * 1001),
+    # short constructed string
+    _segmented(0x24, [b'a' * 500]),
+    # short inner segment
+    _segmented(0x24, [b'a' * 999, b'a' * 2]),
+    # long final segment
+    _segmented(0x24, [b'a' * 1000, b'a' * 1001]),
+    # nested segment
+    b'\x24\x80' + _primitive(0x04, b'a' * 1000) + _segmented(0x24, [b'a']) + b'\x00\x00',
+])
+def test_invalid_segmentation(substrate):
+    with pytest.raises(PyAsn1Error):
+        decode_cer(substrate, univ.OctetString())
+
+
+@pytest.mark.parametrize('substrate_hex,asn1_spec', [
+    ('010101', univ.Boolean()),
+    ('02020001', univ.Integer()),
+    ('0302010F', univ.BitString()),
+    ('31800201020201010000', univ.SetOf(univ.Integer())),
+])
+def test_common_rules(substrate_hex, asn1_spec):
+    with pytest.raises(PyAsn1Error):
+        decode_cer(bytes.fromhex(substrate_hex), asn1_spec)
@@ -48,6 +48,12 @@ def test_decode_explicit():
     assert str(decoded['explicit']) == 'ABC'
 
 
+def test_explicit_value_error_offset():
+    # the invalid PrintableString value follows the two header octets of the explicit tag at offset 2
+    with pytest.raises(error.PyAsn1Error, match='near substrate offset 4:'):
+        _wrapper(b'3007A0051303410043', ExplicitSequence())
+
+
 def test_invalid_nested_explicit_substrate():
     with pytest.raises(error.PyAsn1Error):
         decoded, _ = _wrapper(b'3007A005A003130130', ExplicitSequence())
@@ -58,3 +64,40 @@ def test_too_deep_nested_explicit_substrate():
 
     with pytest.raises(error.PyAsn1Error):
         decoded, _ = _wrapper(substrate, ExplicitSequence())
+
+
+class MixedClassSequence(univ.Sequence):
+    componentType = namedtype.NamedTypes(
+        namedtype.NamedType('context', univ.Integer().subtype(
+            implicitTag=tag.Tag(tag.tagClassContext, tag.tagFormatSimple, 1))),
+        namedtype.NamedType('application', univ.Integer().subtype(
+            implicitTag=tag.Tag(tag.tagClassApplication, tag.tagFormatSimple, 1))),
+        namedtype.NamedType('private', univ.Integer().subtype(
+            implicitTag=tag.Tag(tag.tagClassPrivate, tag.tagFormatSimple, 1))),
+        namedtype.NamedType('explicitContext', univ.Integer().subtype(
+            explicitTag=tag.Tag(tag.tagClassContext, tag.tagFormatConstructed, 2))),
+        namedtype.NamedType('explicitApplication', univ.Integer().subtype(
+            explicitTag=tag.Tag(tag.tagClassApplication, tag.tagFormatConstructed, 2))),
+    )
+
+
+def test_decode_repeated_non_universal_tags():
+    substrate = b'3013' + b'810101' + b'410102' + b'C10103' + b'A203020104' + b'6203020105'
+
+    for _ in range(2):
+        decoded, _ = _wrapper(substrate, MixedClassSequence())
+
+        assert [int(decoded[n]) for n in decoded] == [1, 2, 3, 4, 5]
+
+        for name, component in decoded.items():
+            assert component.tagSet == MixedClassSequence.componentType[name].asn1Object.tagSet
+
+
+def test_decode_non_universal_tags_of_different_classes():
+    # the second component has the context tag [1] rather than the application tag [1]
+    with pytest.raises(error.PyAsn1Error):
+        _wrapper(b'3013' + b'810101' + b'810102' + b'C10103' + b'A203020104' + b'6203020105', MixedClassSequence())
+
+    # the fourth component has the explicit application tag [2] rather than the explicit context tag [2]
+    with pytest.raises(error.PyAsn1Error):
+        _wrapper(b'3013' + b'810101' + b'410102' + b'C10103' + b'6203020104' + b'6203020105', MixedClassSequence())
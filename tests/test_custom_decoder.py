@@ -0,0 +1,132 @@
+import pytest
+from pyasn1.error import PyAsn1Error
+from pyasn1.type import base, tag, univ
+
+from pyasn1_fasder import Pyasn1FasderError, decode_der, der_to_jer, register_decoder, unregister_decoder
+
+
+class Celsius(univ.Integer):
+    typeId = base.Asn1Type.getTypeId()
+
+    tagSet = tag.initTagSet(tag.Tag(tag.tagClassApplication, tag.tagFormatSimple, 1))
+
+
+class Opaque(univ.OctetString):
+    typeId = base.Asn1Type.getTypeId()
+
+    tagSet = tag.initTagSet(tag.Tag(tag.tagClassApplication, tag.tagFormatSimple, 2))
+
+
+class Unregistered(univ.OctetString):
+    typeId = base.Asn1Type.getTypeId()
+
+
+class Kelvin(univ.Integer):
+    typeId = base.Asn1Type.getTypeId()
+
+    tagSet = tag.initTagSet(tag.Tag(tag.tagClassApplication, tag.tagFormatSimple, 3))
+
+
+class Fahrenheit(univ.Integer):
+    typeId = base.Asn1Type.getTypeId()
+
+    tagSet = tag.initTagSet(tag.Tag(tag.tagClassApplication, tag.tagFormatSimple, 4))
+
+
+calls = []
+
+
+def decode_celsius(asn1_spec, value, tag_set, offset):
+    calls.append((value, tag_set, offset))
+
+    return asn1_spec.clone(int.from_bytes(value, 'big', signed=True))
+
+
+register_decoder(Celsius, decode_celsius)
+def decode_kelvin(asn1_spec, value, tag_set, offset):
+    if value[0] & 0x80:
+        raise ValueError('Negative temperature')
+
+    return asn1_spec.clone(int.from_bytes(value, 'big'))
+
+
+register_decoder(Opaque, univ.OctetString)
+register_decoder(Kelvin, decode_kelvin)
+
+
+def test_callable_decoder():
+    calls.clear()
+
+    decoded, _ = decode_der(bytes.fromhex('30034101ec'), univ.SequenceOf(componentType=Celsius()))
+
+    assert decoded[0] == -20
+    assert isinstance(decoded[0], Celsius)
+    assert calls == [(b'\xec', Celsius.tagSet, 2)]
+
+
+def test_native_decoder_alias():
+    decoded, _ = decode_der(bytes.fromhex('42026162'), Opaque())
+
+    assert decoded == b'ab'
+    assert isinstance(decoded, Opaque)
+
+
+def test_decoder_error_is_wrapped():
+    with pytest.raises(Pyasn1FasderError,
+                       match='Error decoding "Kelvin" TLV near substrate offset 2: Negative temperature') as e:
+        decode_der(bytes.fromhex('30034301ec'), univ.SequenceOf(componentType=Kelvin()))
+
+    assert isinstance(e.value.__cause__, ValueError)
+
+
+def test_tag_mismatch_is_rejected():
+    with pytest.raises(PyAsn1Error):
+        decode_der(bytes.fromhex('0201ec'), Celsius())
+
+
+def test_trailing_octets_are_rejected():
+    with pytest.raises(PyAsn1Error):
+        decode_der(bytes.fromhex('4101ec00'), Celsius())
+
+
+def test_unregistered_type():
+    with pytest.raises(PyAsn1Error):
+        decode_der(bytes.fromhex('04026162'), Unregistered())
+
+
+def test_builtin_type_cannot_be_replaced():
+    with pytest.raises(ValueError):
+        register_decoder(univ.Integer, decode_celsius)
+
+
+def test_alias_requires_native_decoder():
+    with pytest.raises(ValueError):
+        register_decoder(Unregistered, Celsius)
+
+
+def test_unregister_decoder():
+    register_decoder(Fahrenheit, univ.Integer)
+
+    decoded, _ = decode_der(bytes.fromhex('440120'), Fahrenheit())
+    assert decoded == 32
+
+    unregister_decoder(Fahrenheit())
+
+    with pytest.raises(PyAsn1Error):
+        decode_der(bytes.fromhex('440120'), Fahrenheit())
+
+    with pytest.raises(ValueError):
+        unregister_decoder(Fahrenheit)
+
+
+def test_builtin_decoder_cannot_be_unregistered():
+    with pytest.raises(ValueError):
+        unregister_decoder(univ.Integer)
+
+
+def test_jer():
+    assert der_to_jer(bytes.fromhex('42020102'), Opaque()) == '"0102"'
+
+    # the values decoded by callables are not known to be representable in JER
+    with pytest.raises(Pyasn1FasderError, match='cannot be encoded in JER'):
+        der_to_jer(bytes.fromhex('41011e'), Celsius())
@@ -0,0 +1,85 @@
+import os
+import subprocess
+import sys
+
+import pytest
+
+
+def _find_dump_binary():
+    if 'FASDER_DUMP' in os.environ:
+        return os.environ['FASDER_DUMP']
+
+    name = 'fasder-dump.exe' if sys.platform == 'win32' else 'fasder-dump'
+    target_dir = os.path.join(os.path.dirname(os.path.dirname(os.path.abspath(__file__))), 'target')
+
+    for profile in ('release', 'debug'):
+        path = os.path.join(target_dir, profile, name)
+
+        if os.path.exists(path):
+            return path
+
+    return None
+
+
+DUMP_BINARY = _find_dump_binary()
+
+pytestmark = pytest.mark.skipif(DUMP_BINARY is None, reason='fasder-dump binary has not been built')
+
+
+def _dump(substrate_hex):
+    return subprocess.run([DUMP_BINARY, '--hex'], input=substrate_hex.encode(), capture_output=True)
+
+
+def _wrap(tag, substrate):
+    length = len(substrate).to_bytes(4, 'big').lstrip(b'\x00')
+    length_octets = length if len(substrate) < 0x80 else bytes([0x80 | len(length)]) + length
+
+    return bytes([tag]) + length_octets + substrate
+
+
+def _nest(tag, depth):
+    substrate = bytes.fromhex('3000')
+
+    for _ in range(depth):
+        substrate = _wrap(tag, substrate)
+
+    return substrate
+
+
+def test_valid():
+    result = _dump('3106020101020102')
+
+    assert result.returncode == 0
+    assert b'INTEGER 1' in result.stdout
+
+
+@pytest.mark.parametrize('substrate_hex,description', [
+    ('0100', b'Invalid BOOLEAN value length of 0 octets'),
+    ('010101', b'Non-canonical BOOLEAN encoding'),
+    ('0500', None),
+    ('0600', b'OID value is empty'),
+    ('0300', b'Substrate under-run in BIT STRING'),
+    ('0405AABB', b'Length exceeds remaining substrate'),
+])
+def test_malformed(substrate_hex, description):
+    result = _dump(substrate_hex)
+
+    assert result.returncode == (0 if description is None else 1)
+
+    if description is not None:
+        assert description in result.stdout
+
+
+@pytest.mark.parametrize('wrapper_tag', [0x30, 0xA0])
+def test_deep_nesting(wrapper_tag):
+    result = _dump(_nest(wrapper_tag, 100000).hex())
+
+    assert result.returncode == 1
+    assert b'Exceeded limit of 64 nested TLVs' in result.stdout
+
+
+def test_deep_encapsulation():
+    result = _dump(_wrap(0x04, _nest(0x30, 100000)).hex())
+
+    assert result.returncode == 0
+    assert b'encapsulates' not in result.stdout
@@ -0,0 +1,141 @@
+import pytest
+from pyasn1.error import PyAsn1Error
+from pyasn1.type import namedtype, namedval, univ
+
+from pyasn1_fasder import IntegerRule, IntegerRuleError, decode_ber, decode_cer, decode_der, decode_der_native
+
+
+class Color(univ.Enumerated):
+    namedValues = namedval.NamedValues(('red', 0), ('green', 1))
+
+
+class Certificate(univ.Sequence):
+    componentType = namedtype.NamedTypes(
+        namedtype.NamedType('version', univ.Integer()),
+        namedtype.NamedType('serialNumber', univ.Integer()),
+        namedtype.NamedType('color', Color()),
+        namedtype.NamedType('extensions', univ.SequenceOf(componentType=univ.Integer()))
+    )
+
+
+SERIAL_NUMBER_RULE = IntegerRule(max_octets=20, positive=True)
+
+
+def _encode(version=2, serial_number=1, color=0, extensions=()):
+    def integer(tag, value):
+        octets = value.to_bytes(max(value, ~value).bit_length() // 8 + 1, 'big', signed=True)
+
+        return bytes([tag, len(octets)]) + octets
+
+    components = integer(0x02, version) + integer(0x02, serial_number) + integer(0x0A, color)
+    extension_octets = b''.join(integer(0x02, e) for e in extensions)
+    components += bytes([0x30, len(extension_octets)]) + extension_octets
+
+    return bytes([0x30, len(components)]) + components
+
+
+@pytest.mark.parametrize('serial_number', [1, 0x7F, 2 ** 159 - 1])
+def test_serial_number_valid(serial_number):
+    decoded, _ = decode_der(_encode(serial_number=serial_number), Certificate(),
+                            integerRules={('serialNumber',): SERIAL_NUMBER_RULE})
+
+    assert decoded['serialNumber'] == serial_number
+
+
+@pytest.mark.parametrize('serial_number,description', [
+    (0, 'Value is not positive'),
+    (-1, 'Value is not positive'),
+    (2 ** 159, 'Value length of 21 octets exceeds the maximum of 20 octets'),
+])
+def test_serial_number_invalid(serial_number, description):
+    with pytest.raises(IntegerRuleError) as e:
+        decode_der(_encode(serial_number=serial_number), Certificate(),
+                   integerRules={('serialNumber',): SERIAL_NUMBER_RULE})
+
+    assert e.value.description == description
+    assert e.value.offset == 5
+    assert e.value.path == ('serialNumber',)
+
+
+def test_error_message():
+    with pytest.raises(IntegerRuleError) as e:
+        decode_der(_encode(serial_number=0), Certificate(), integerRules={('serialNumber',): SERIAL_NUMBER_RULE})
+
+    assert str(e.value) == "Value is not positive near substrate offset 5 in component ('serialNumber',)"
+
+
+def test_error_is_pyasn1_error():
+    with pytest.raises(PyAsn1Error):
+        decode_der(_encode(serial_number=0), Certificate(), integerRules={('serialNumber',): SERIAL_NUMBER_RULE})
+
+
+def test_min_octets():
+    rules = {('serialNumber',): IntegerRule(min_octets=2)}
+
+    decode_der(_encode(serial_number=0x1234), Certificate(), integerRules=rules)
+
+    with pytest.raises(IntegerRuleError, match='Value length of 1 octets is less than the minimum of 2 octets'):
+        decode_der(_encode(serial_number=0x12), Certificate(), integerRules=rules)
+
+
+def test_non_zero():
+    rules = {('version',): IntegerRule(non_zero=True)}
+
+    decode_der(_encode(version=-1), Certificate(), integerRules=rules)
+
+    with pytest.raises(IntegerRuleError, match='Value is zero'):
+        decode_der(_encode(version=0), Certificate(), integerRules=rules)
+
+
+def test_enumerated():
+    with pytest.raises(IntegerRuleError) as e:
+        decode_der(_encode(color=0), Certificate(), integerRules={('color',): IntegerRule(non_zero=True)})
+
+    assert e.value.path == ('color',)
+
+
+def test_sequence_of_component():
+    rules = {('extensions', 1): IntegerRule(positive=True)}
+
+    decode_der(_encode(extensions=(-1, 1)), Certificate(), integerRules=rules)
+
+    with pytest.raises(IntegerRuleError) as e:
+        decode_der(_encode(extensions=(1, -1)), Certificate(), integerRules=rules)
+
+    assert e.value.path == ('extensions', 1)
+
+
+def test_other_components_unaffected():
+    decoded, _ = decode_der(_encode(version=0, serial_number=5), Certificate(),
+                            integerRules={('serialNumber',): SERIAL_NUMBER_RULE})
+
+    assert decoded['version'] == 0
+
+
+def test_root_path():
+    with pytest.raises(IntegerRuleError) as e:
+        decode_der(bytes.fromhex('020100'), univ.Integer(), integerRules={(): IntegerRule(positive=True)})
+
+    assert e.value.offset == 0
+    assert e.value.path == ()
+
+
+def test_native():
+    with pytest.raises(IntegerRuleError):
+        decode_der_native(_encode(serial_number=-5), Certificate(),
+                          integerRules={('serialNumber',): SERIAL_NUMBER_RULE})
+
+
+def test_ber():
+    with pytest.raises(IntegerRuleError):
+        decode_ber(_encode(serial_number=-5), Certificate(), integerRules={('serialNumber',): SERIAL_NUMBER_RULE})
+
+
+def test_cer():
+    with pytest.raises(IntegerRuleError):
+        decode_cer(bytes.fromhex('0201FF'), univ.Integer(), integerRules={(): IntegerRule(positive=True)})
+
+
+def test_invalid_rule():
+    with pytest.raises(TypeError):
+        decode_der(_encode(), Certificate(), integerRules={('serialNumber',): (None, 20, True, False)})
@@ -0,0 +1,139 @@
+import base64
+import json
+
+import pytest
+from pyasn1.codec.der.encoder import encode
+from pyasn1.error import PyAsn1Error
+from pyasn1.type import char, namedtype, namedval, tag, univ, useful
+
+from pyasn1_fasder import der_to_jer
+
+
+class Color(univ.Enumerated):
+    namedValues = namedval.NamedValues(('red', 0), ('green', 1))
+
+
+class Alternative(univ.Choice):
+    componentType = namedtype.NamedTypes(
+        namedtype.NamedType('number', univ.Integer()),
+        namedtype.NamedType('text', char.UTF8String()),
+    )
+
+
+class Record(univ.Sequence):
+    componentType = namedtype.NamedTypes(
+        namedtype.NamedType('flag', univ.Boolean()),
+        namedtype.NamedType('number', univ.Integer()),
+        namedtype.NamedType('color', Color()),
+        namedtype.NamedType('oid', univ.ObjectIdentifier()),
+        namedtype.NamedType('bits', univ.BitString()),
+        namedtype.NamedType('octets', univ.OctetString()),
+        namedtype.NamedType('nothing', univ.Null()),
+        namedtype.NamedType('text', char.UTF8String()),
+        namedtype.NamedType('time', useful.GeneralizedTime()),
+        namedtype.NamedType('numbers', univ.SetOf(univ.Integer())),
+        namedtype.NamedType('alternative', Alternative()),
+        namedtype.OptionalNamedType('absent', univ.Integer().subtype(implicitTag=tag.Tag(tag.tagClassContext,
+                                                                                         tag.tagFormatSimple, 0))),
+        namedtype.DefaultedNamedType('version', univ.Integer(5)),
+    )
+
+
+def _create_record():
+    record = Record()
+    record['flag'] = True
+    record['number'] = -2 ** 70
+    record['color'] = 'green'
+    record['oid'] = '1.2.840.113549'
+    record['bits'] = univ.BitString("'1010101'B")
+    record['octets'] = b'\x00\xfe'
+    record['nothing'] = ''
+    record['text'] = 'say "héllo"\n'
+    record['time'] = '20240229120000Z'
+    record['numbers'].extend([1, 2])
+    record['alternative']['number'] = 7
+
+    return record
+
+
+def test_values():
+    jer = der_to_jer(encode(_create_record()), Record())
+
+    assert json.loads(jer) == {
+        'flag': True,
+        'number': -2 ** 70,
+        'color': 'green',
+        'oid': '1.2.840.113549',
+        'bits': {'value': 'AA', 'length': 7},
+        'octets': '00FE',
+        'nothing': None,
+        'text': 'say "héllo"\n',
+        'time': '20240229120000Z',
+        'numbers': [1, 2],
+        'alternative': {'number': 7},
+    }
+
+
+@pytest.mark.parametrize('octets', [b'', b'a', b'ab', b'abc', b'abcd'])
+def test_base64_octets(octets):
+    jer = der_to_jer(encode(univ.OctetString(octets)), univ.OctetString(), octetsEncoding='base64')
+
+    assert json.loads(jer) == base64.b64encode(octets).decode()
+
+
+@pytest.mark.parametrize('bits,expected', [
+    ("''B", {'value': '', 'length': 0}),
+    ("'00000000'B", {'value': '00', 'length': 8}),
+    ("'000000001'B", {'value': '0080', 'length': 9}),
+])
+def test_bit_string(bits, expected):
+    jer = der_to_jer(encode(univ.BitString(bits)), univ.BitString())
+
+    assert json.loads(jer) == expected
+
+
+def test_unnamed_enumerated_value():
+    assert der_to_jer(bytes.fromhex('0a0105'), Color()) == '5'
+
+
+def test_invalid_der_is_rejected():
+    with pytest.raises(PyAsn1Error):
+        der_to_jer(bytes.fromhex('02020001'), univ.Integer())
+
+
+def test_unsupported_octets_encoding():
+    with pytest.raises(ValueError):
+        der_to_jer(bytes.fromhex('0400'), univ.OctetString(), octetsEncoding='base32')
+
+
+class Records(univ.SequenceOf):
+    componentType = Record()
+
+
+def test_nested_values():
+    records = Records()
+    records.extend([_create_record(), _create_record()])
+    records[1]['version'] = 6
+    records[1]['alternative']['text'] = 'seven'
+
+    jer = json.loads(der_to_jer(encode(records), Records()))
+
+    assert [r['alternative'] for r in jer] == [{'number': 7}, {'text': 'seven'}]
+    assert ['version' in r for r in jer] == [False, True]
+    assert jer[1]['version'] == 6
+
+
+def test_explicitly_tagged_values():
+    spec = univ.SequenceOf(univ.Integer().subtype(explicitTag=tag.Tag(tag.tagClassContext,
+                                                                      tag.tagFormatConstructed, 1)))
+    value = spec.clone()
+    value.extend([1, 2])
+
+    assert der_to_jer(encode(value), spec) == '[1,2]'
+
+
+def test_encoded_default_value_is_rejected():
+    spec = univ.Sequence(componentType=namedtype.NamedTypes(namedtype.DefaultedNamedType('version', univ.Integer(5))))
+
+    with pytest.raises(PyAsn1Error):
+        der_to_jer(bytes.fromhex('3003020105'), spec)
@@ -0,0 +1,219 @@
+import pytest
+from pyasn1.codec.der.encoder import encode
+from pyasn1.error import PyAsn1Error
+from pyasn1.type import char, namedtype, tag, univ
+
+from pyasn1_fasder import LazyValue, decode_der, decode_der_lazy
+
+
+class AlgorithmIdentifier(univ.Sequence):
+    componentType = namedtype.NamedTypes(
+        namedtype.NamedType('algorithm', univ.ObjectIdentifier()),
+        namedtype.OptionalNamedType('parameters', univ.Any()),
+    )
+
+
+class Name(univ.Choice):
+    componentType = namedtype.NamedTypes(
+        namedtype.NamedType('commonName', char.UTF8String()),
+        namedtype.NamedType('serial', univ.Integer()),
+    )
+
+
+class TbsCertificate(univ.Sequence):
+    componentType = namedtype.NamedTypes(
+        namedtype.DefaultedNamedType('version', univ.Integer(0).subtype(
+            explicitTag=tag.Tag(tag.tagClassContext, tag.tagFormatSimple, 0))),
+        namedtype.NamedType('serialNumber', univ.Integer()),
+        namedtype.NamedType('signature', AlgorithmIdentifier()),
+        namedtype.NamedType('subjects', univ.SequenceOf(Name())),
+        namedtype.NamedType('issuer', Name().subtype(
+            explicitTag=tag.Tag(tag.tagClassContext, tag.tagFormatConstructed, 1))),
+        namedtype.OptionalNamedType('extensions', univ.OctetString().subtype(
+            implicitTag=tag.Tag(tag.tagClassContext, tag.tagFormatSimple, 3))),
+    )
+
+
+class Certificate(univ.Sequence):
+    componentType = namedtype.NamedTypes(
+        namedtype.NamedType('tbsCertificate', TbsCertificate()),
+        namedtype.NamedType('signatureValue', univ.BitString()),
+    )
+
+
+def _create_certificate(version=2):
+    certificate = Certificate()
+
+    tbs_certificate = certificate['tbsCertificate']
+    tbs_certificate['version'] = version
+    tbs_certificate['serialNumber'] = 2 ** 64
+    tbs_certificate['signature']['algorithm'] = '1.2.840.113549.1.1.11'
+    tbs_certificate['subjects'].append(Name())
+    tbs_certificate['subjects'][0]['commonName'] = 'a'
+    tbs_certificate['subjects'].append(Name())
+    tbs_certificate['subjects'][1]['serial'] = 5
+    tbs_certificate['issuer']['commonName'] = 'b'
+
+    certificate['signatureValue'] = univ.BitString(hexValue='abcd')
+
+    return certificate
+
+
+SUBSTRATE = encode(_create_certificate())
+
+
+def test_components():
+    decoded, _ = decode_der(SUBSTRATE, Certificate())
+
+    lazy = decode_der_lazy(SUBSTRATE, Certificate())
+
+    assert isinstance(lazy, LazyValue)
+    assert list(lazy) == ['tbsCertificate', 'signatureValue']
+    assert lazy['signatureValue'] == decoded['signatureValue']
+
+    tbs_certificate = lazy['tbsCertificate']
+
+    assert isinstance(tbs_certificate, LazyValue)
+    assert tbs_certificate['version'] == 2
+    assert tbs_certificate['serialNumber'] == 2 ** 64
+    assert tbs_certificate['signature']['algorithm'] == decoded['tbsCertificate']['signature']['algorithm']
+    assert tbs_certificate['signature']['parameters'] is None
+    assert tbs_certificate['extensions'] is None
+
+    subjects = tbs_certificate['subjects']
+
+    assert len(subjects) == 2
+    assert subjects[0].getName() == 'commonName'
+    assert subjects[0].getComponent() == 'a'
+    assert subjects[-1]['serial'] == 5
+    assert subjects[-1]['commonName'] is None
+    assert [s.getName() for s in subjects] == ['commonName', 'serial']
+
+    assert tbs_certificate['issuer'].getComponent() == 'b'
+
+    assert lazy.decode() == decoded
+    assert tbs_certificate.decode() == decoded['tbsCertificate']
+
+
+def test_positions():
+    positions = {}
+
+    decode_der(SUBSTRATE, Certificate(), positions=positions)
+
+    lazy = decode_der_lazy(SUBSTRATE, Certificate())
+
+    assert lazy.position == positions[()]
+    assert lazy['tbsCertificate'].position == positions[('tbsCertificate',)]
+    assert lazy['tbsCertificate']['subjects'][1].position == positions[('tbsCertificate', 'subjects', 1)]
+
+
+def test_components_are_cached():
+    lazy = decode_der_lazy(bytearray(SUBSTRATE), Certificate())
+
+    assert lazy['tbsCertificate'] is lazy['tbsCertificate']
+    assert lazy['signatureValue'] is lazy['signatureValue']
+
+
+def test_absent_defaulted_component():
+    lazy = decode_der_lazy(encode(_create_certificate(version=0)), Certificate())
+
+    assert list(lazy['tbsCertificate'])[0] == 'serialNumber'
+    assert lazy['tbsCertificate']['version'] == 0
+
+
+def test_invalid_keys():
+    lazy = decode_der_lazy(SUBSTRATE, Certificate())
+
+    with pytest.raises(KeyError):
+        lazy['unknown']
+
+    with pytest.raises(TypeError):
+        lazy[0]
+
+    with pytest.raises(TypeError):
+        lazy['tbsCertificate']['subjects']['commonName']
+
+    with pytest.raises(IndexError):
+        lazy['tbsCertificate']['subjects'][2]
+
+    with pytest.raises(TypeError):
+        lazy.getName()
+
+
+def test_errors_are_raised_on_access():
+    # the serial number is not minimally encoded, which is only detected if it is decoded
+    substrate = bytes.fromhex('3011300a0203000001020101300030000301ff')
+
+    class Partial(univ.Sequence):
+        componentType = namedtype.NamedTypes(
+            namedtype.NamedType('tbsCertificate', univ.Sequence(componentType=namedtype.NamedTypes(
+                namedtype.NamedType('serialNumber', univ.Integer()),
+                namedtype.NamedType('version', univ.Integer()),
+                namedtype.NamedType('signature', univ.Sequence()),
+            ))),
+            namedtype.NamedType('signatureValue', univ.Sequence()),
+            namedtype.NamedType('bits', univ.BitString()),
+        )
+
+    lazy = decode_der_lazy(substrate, Partial())
+
+    assert lazy['tbsCertificate']['version'] == 1
+
+    with pytest.raises(PyAsn1Error):
+        lazy['tbsCertificate']['serialNumber']
+
+    with pytest.raises(PyAsn1Error):
+        lazy['bits']
+
+    with pytest.raises(PyAsn1Error):
+        lazy.decode()
+
+
+@pytest.mark.parametrize('substrate_hex', [
+    # missing required component
+    '30053003020101',
+    # explicitly encoded default value
+    '3008a003020100020101',
+    # excessive components
+    '3009020101020101020101',
+])
+def test_framing_errors(substrate_hex):
+    class Value(univ.Sequence):
+        componentType = namedtype.NamedTypes(
+            namedtype.DefaultedNamedType('version', univ.Integer(0).subtype(
+                explicitTag=tag.Tag(tag.tagClassContext, tag.tagFormatSimple, 0))),
+            namedtype.NamedType('serialNumber', univ.Integer()),
+        )
+
+    substrate = bytes.fromhex(substrate_hex)
+
+    with pytest.raises(PyAsn1Error):
+        decode_der_lazy(substrate, Value())
+
+    # the components of nested values are framed when they are first accessed
+    lazy = decode_der_lazy(bytes([0x30, len(substrate)]) + substrate, univ.SequenceOf(Value()))
+
+    with pytest.raises(PyAsn1Error):
+        len(lazy[0])
+
+
+def test_out_of_order_set_of():
+    lazy = decode_der_lazy(bytes.fromhex('30083106020102020101'), univ.SequenceOf(univ.SetOf(univ.Integer())))
+
+    with pytest.raises(PyAsn1Error, match='Out of order component at index 1'):
+        lazy[0][0]
+
+
+@pytest.mark.parametrize('substrate_hex', [
+    # trailing octets
+    '3000ff',
+    # mismatched tag
+    '3100',
+])
+def test_root_is_checked(substrate_hex):
+    with pytest.raises(PyAsn1Error):
+        decode_der_lazy(bytes.fromhex(substrate_hex), univ.Sequence())
+
+
+def test_non_constructed_value():
+    assert decode_der_lazy(bytes.fromhex('020105'), univ.Integer()) == 5
@@ -0,0 +1,77 @@
+import pytest
+from pyasn1.error import PyAsn1Error
+from pyasn1.type import namedtype, univ
+
+from pyasn1_fasder import TlvLengthError, decode_ber, decode_der, validate_der
+
+
+class Record(univ.Sequence):
+    componentType = namedtype.NamedTypes(
+        namedtype.NamedType('value', univ.OctetString())
+    )
+
+
+@pytest.mark.parametrize('substrate_hex,kind,declared_length,available_length,length_octets_hex', [
+    ('04', 'truncated', None, 0, ''),
+    ('04830001', 'truncated', None, 0, '830001'),
+    ('0480', 'indefinite', None, 0, '80'),
+    ('04810500000000', 'non-minimal', 5, 4, '8105'),
+    ('0482000500000000', 'non-minimal', 5, 4, '820005'),
+    ('04FF', 'oversized', None, 0, 'ff'),
+    ('0405AABB', 'exceeds-input', 5, 2, '05'),
+])
+def test_length_error(substrate_hex, kind, declared_length, available_length, length_octets_hex):
+    with pytest.raises(TlvLengthError) as e:
+        decode_der(bytes.fromhex(substrate_hex), asn1Spec=univ.OctetString())
+
+    assert e.value.kind == kind
+    assert e.value.offset == 0
+    assert e.value.declared_length == declared_length
+    assert e.value.available_length == available_length
+    assert e.value.length_octets == bytes.fromhex(length_octets_hex)
+
+
+def test_length_error_message():
+    with pytest.raises(TlvLengthError) as e:
+        decode_der(bytes.fromhex('0482000500000000'), asn1Spec=univ.OctetString())
+
+    assert str(e.value) == (
+        'Error reading TLV header near substrate offset 0: Non-minimal length encoding '
+        '(length octets: 82 00 05, declared length: 5, available octets: 4)'
+    )
+
+
+def test_length_error_is_pyasn1_error():
+    with pytest.raises(PyAsn1Error):
+        decode_der(bytes.fromhex('0405AABB'), asn1Spec=univ.OctetString())
+
+
+def test_nested_length_error():
+    with pytest.raises(TlvLengthError) as e:
+        decode_der(bytes.fromhex('300404050102'), asn1Spec=Record())
+
+    assert e.value.kind == 'exceeds-input'
+    assert e.value.offset == 2
+    assert e.value.declared_length == 5
+    assert e.value.available_length == 2
+
+
+def test_ber_non_minimal_length():
+    decoded, findings = decode_ber(bytes.fromhex('0481020102'), asn1Spec=univ.OctetString())
+
+    assert decoded == univ.OctetString(hexValue='0102')
+    assert len(findings) == 1
+
+
+def test_ber_length_error():
+    with pytest.raises(TlvLengthError) as e:
+        decode_ber(bytes.fromhex('0405AABB'), asn1Spec=univ.OctetString())
+
+    assert e.value.kind == 'exceeds-input'
+
+
+def test_validate_length_error():
+    findings = validate_der(bytes.fromhex('0405AABB'))
+
+    assert len(findings) == 1
+    assert 'declared length: 5, available octets: 2' in findings[0].description
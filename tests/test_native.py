@@ -0,0 +1,129 @@
+import datetime
+
+import pytest
+from pyasn1.codec.der.encoder import encode
+from pyasn1.error import PyAsn1Error
+from pyasn1.type import char, namedtype, tag, univ, useful
+
+from pyasn1_fasder import decode_der_native
+
+
+class Alternative(univ.Choice):
+    componentType = namedtype.NamedTypes(
+        namedtype.NamedType('number', univ.Integer()),
+        namedtype.NamedType('text', char.UTF8String()),
+    )
+
+
+class Record(univ.Sequence):
+    componentType = namedtype.NamedTypes(
+        namedtype.NamedType('flag', univ.Boolean()),
+        namedtype.NamedType('oid', univ.ObjectIdentifier()),
+        namedtype.NamedType('bits', univ.BitString()),
+        namedtype.NamedType('octets', univ.OctetString()),
+        namedtype.NamedType('nothing', univ.Null()),
+        namedtype.NamedType('printable', char.PrintableString()),
+        namedtype.NamedType('bmp', char.BMPString()),
+        namedtype.NamedType('utc', useful.UTCTime()),
+        namedtype.NamedType('generalized', useful.GeneralizedTime()),
+        namedtype.NamedType('numbers', univ.SequenceOf(univ.Integer())),
+        namedtype.NamedType('alternative', Alternative()),
+        namedtype.OptionalNamedType('absent', univ.Integer().subtype(implicitTag=tag.Tag(tag.tagClassContext,
+                                                                                         tag.tagFormatSimple, 0))),
+        namedtype.DefaultedNamedType('version', univ.Integer(5)),
+    )
+
+
+def _create_record():
+    record = Record()
+    record['flag'] = True
+    record['oid'] = '1.2.840.113549'
+    record['bits'] = univ.BitString("'1010101'B")
+    record['octets'] = b'abc'
+    record['nothing'] = ''
+    record['printable'] = 'Test'
+    record['bmp'] = 'café'
+    record['utc'] = '491231235959Z'
+    record['generalized'] = '20240229120000.25Z'
+    record['numbers'].extend([-1, 2 ** 70])
+    record['alternative']['text'] = 'hello'
+
+    return record
+
+
+def test_values():
+    assert decode_der_native(encode(_create_record()), Record()) == {
+        'flag': True,
+        'oid': '1.2.840.113549',
+        'bits': (b'\xaa', 1),
+        'octets': b'abc',
+        'nothing': None,
+        'printable': 'Test',
+        'bmp': 'café',
+        'utc': datetime.datetime(2049, 12, 31, 23, 59, 59, tzinfo=datetime.timezone.utc),
+        'generalized': datetime.datetime(2024, 2, 29, 12, 0, 0, 250000, tzinfo=datetime.timezone.utc),
+        'numbers': [-1, 2 ** 70],
+        'alternative': {'text': 'hello'},
+        'version': 5,
+    }
+
+
+def test_utc_time_pivot():
+    decoded = decode_der_native(encode(useful.UTCTime('500101000000Z')), useful.UTCTime())
+
+    assert decoded.year == 1950
+
+
+def test_tagged_choice():
+    asn1_spec = Alternative().subtype(explicitTag=tag.Tag(tag.tagClassContext, tag.tagFormatConstructed, 1))
+
+    assert decode_der_native(bytes.fromhex('a103020107'), asn1_spec) == {'number': 7}
+
+
+def test_memoryview_values():
+    substrate = bytearray(encode(univ.OctetString(b'abc')))
+
+    decoded = decode_der_native(substrate, univ.OctetString(), memoryviewValues=True)
+
+    assert isinstance(decoded, memoryview)
+    assert decoded == b'abc'
+
+
+@pytest.mark.parametrize('asn1_spec,substrate_hex', [
+    (useful.UTCTime(), '170d3439313233313233353936305a'),
+    (useful.GeneralizedTime(), '180f32303233303232393132303030305a'),
+    (useful.GeneralizedTime(), '181232303234303232393132303030302e35305a'),
+    (char.UTF8String(), '0c01ff'),
+    (char.IA5String(), '1601ff'),
+])
+def test_invalid_values(asn1_spec, substrate_hex):
+    with pytest.raises(PyAsn1Error):
+        decode_der_native(bytes.fromhex(substrate_hex), asn1_spec)
+
+
+def test_der_rules_are_checked():
+    with pytest.raises(PyAsn1Error):
+        decode_der_native(bytes.fromhex('02020001'), univ.Integer())
+
+
+def test_explicitly_encoded_default_value():
+    record = _create_record()
+    substrate = encode(record)
+
+    # append the default value of the last component to the encoding
+    substrate = bytes([0x30, substrate[1] + 3]) + substrate[2:] + bytes.fromhex('020105')
+
+    with pytest.raises(PyAsn1Error):
+        decode_der_native(substrate, Record())
+
+
+@pytest.mark.parametrize('substrate_hex,value', [
+    ('020100', 0),
+    ('0201ff', -1),
+    ('02087fffffffffffffff', 2 ** 63 - 1),
+    ('02088000000000000000', -2 ** 63),
+    ('0209008000000000000000', 2 ** 63),
+    ('0209ff7fffffffffffffff', -2 ** 63 - 1),
+])
+def test_integer_values(substrate_hex, value):
+    assert decode_der_native(bytes.fromhex(substrate_hex), univ.Integer()) == value
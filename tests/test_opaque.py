@@ -0,0 +1,149 @@
+import pytest
+from pyasn1.codec.der.encoder import encode
+from pyasn1.error import PyAsn1Error
+from pyasn1.type import char, namedtype, tag, univ
+
+from pyasn1_fasder import decode_ber, decode_der, decode_der_native
+
+
+class AlgorithmIdentifier(univ.Sequence):
+    componentType = namedtype.NamedTypes(
+        namedtype.NamedType('algorithm', univ.ObjectIdentifier()),
+        namedtype.OptionalNamedType('parameters', univ.Any()),
+    )
+
+
+class Name(univ.Choice):
+    componentType = namedtype.NamedTypes(
+        namedtype.NamedType('commonName', char.UTF8String()),
+        namedtype.NamedType('serial', univ.Integer()),
+    )
+
+
+class Certificate(univ.Sequence):
+    componentType = namedtype.NamedTypes(
+        namedtype.NamedType('serialNumber', univ.Integer()),
+        namedtype.NamedType('signature', AlgorithmIdentifier()),
+        namedtype.NamedType('issuer', Name().subtype(
+            explicitTag=tag.Tag(tag.tagClassContext, tag.tagFormatConstructed, 1))),
+        namedtype.NamedType('signatureValue', univ.BitString()),
+    )
+
+
+def _create_certificate():
+    certificate = Certificate()
+
+    certificate['serialNumber'] = 5
+    certificate['signature']['algorithm'] = '1.2.840.113549.1.1.11'
+    certificate['signature']['parameters'] = encode(univ.Null())
+    certificate['issuer']['commonName'] = 'a'
+    certificate['signatureValue'] = univ.BitString(hexValue='abcd')
+
+    return certificate
+
+
+CERTIFICATE = _create_certificate()
+SUBSTRATE = encode(CERTIFICATE)
+
+
+@pytest.mark.parametrize('opaque_component', [
+    ('signature',),
+    AlgorithmIdentifier,
+    AlgorithmIdentifier(),
+])
+def test_opaque_component(opaque_component):
+    decoded, _ = decode_der(SUBSTRATE, Certificate(), opaqueComponents=[opaque_component])
+
+    assert isinstance(decoded['signature'], univ.Any)
+    assert decoded['signature'].asOctets() == encode(CERTIFICATE['signature'])
+    assert decoded['serialNumber'] == 5
+
+
+def test_explicit_tags_are_included():
+    decoded, _ = decode_der(SUBSTRATE, Certificate(), opaqueComponents=[('issuer',)])
+
+    assert decoded['issuer'].asOctets() == bytes.fromhex('a1030c0161')
+
+
+def test_positions():
+    positions = {}
+
+    decode_der(SUBSTRATE, Certificate(), positions=positions, opaqueComponents=[('signature',)])
+
+    assert ('signature',) in positions
+    assert ('signature', 'algorithm') not in positions
+
+
+def test_native_values():
+    decoded = decode_der_native(SUBSTRATE, Certificate(), opaqueComponents=[('signature',), ('issuer',)])
+
+    assert decoded['signature'] == encode(CERTIFICATE['signature'])
+    assert decoded['issuer'] == bytes.fromhex('a1030c0161')
+
+
+def test_round_trip():
+    decoded, _ = decode_der(SUBSTRATE, Certificate(), opaqueComponents=[AlgorithmIdentifier],
+                            verifyRoundTrip=True)
+
+    assert encode(decoded) == SUBSTRATE
+
+
+def test_values_are_not_checked():
+    # the OID is not minimally encoded
+    substrate = bytes.fromhex('3016020105300706038000010500a1030c01610303006162')
+
+    with pytest.raises(PyAsn1Error):
+        decode_der(substrate, Certificate())
+
+    decoded, _ = decode_der(substrate, Certificate(), opaqueComponents=[('signature',)])
+
+    assert decoded['signature'].asOctets() == bytes.fromhex('300706038000010500')
+
+
+@pytest.mark.parametrize('substrate_hex', [
+    # truncated nested TLV
+    '3016020105300706048000010500a1030c01610303006162',
+    # non-minimal nested length
+    '301702010530080681038000010500a1030c01610303006162',
+])
+def test_framing_is_checked(substrate_hex):
+    with pytest.raises(PyAsn1Error):
+        decode_der(bytes.fromhex(substrate_hex), Certificate(), opaqueComponents=[('signature',)])
+
+
+def test_tags_are_checked():
+    substrate = bytes.fromhex('3014020105310506032a0304a1030c01610303006162')
+
+    with pytest.raises(PyAsn1Error, match='Substrate does not match ASN.1 specification'):
+        decode_der(substrate, Certificate(), opaqueComponents=[('signature',)])
+
+
+def test_ber_deviations_are_reported():
+    substrate = bytes.fromhex('301702010530080681038000010500a1030c01610303006162')
+
+    decoded, deviations = decode_ber(substrate, Certificate(), opaqueComponents=[('signature',)])
+
+    assert [d.description for d in deviations] == ['Non-minimal length encoding']
+    assert deviations[0].offset == 7
+
+
+def test_invalid_opaque_component():
+    with pytest.raises(TypeError):
+        decode_der(SUBSTRATE, Certificate(), opaqueComponents=['signature'])
+
+
+class OptionalRecord(univ.Sequence):
+    componentType = namedtype.NamedTypes(
+        namedtype.OptionalNamedType('a', univ.OctetString()),
+        namedtype.DefaultedNamedType('b', univ.Integer(0)),
+    )
+
+
+def test_optional_component_type():
+    decoded, _ = decode_der(bytes.fromhex('300804036162630201FF'), OptionalRecord(),
+                            opaqueComponents=[univ.OctetString, univ.Integer])
+
+    assert isinstance(decoded['a'], univ.Any)
+    assert decoded['a'] == bytes.fromhex('0403616263')
+    assert isinstance(decoded['b'], univ.Any)
+    assert decoded['b'] == bytes.fromhex('0201FF')
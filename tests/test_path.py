@@ -0,0 +1,157 @@
+import pytest
+from pyasn1.codec.der.encoder import encode
+from pyasn1.error import PyAsn1Error
+from pyasn1.type import char, namedtype, tag, univ
+
+from pyasn1_fasder import decode_der, decode_der_path
+
+
+class AlgorithmIdentifier(univ.Sequence):
+    componentType = namedtype.NamedTypes(
+        namedtype.NamedType('algorithm', univ.ObjectIdentifier()),
+        namedtype.OptionalNamedType('parameters', univ.Any()),
+    )
+
+
+class Name(univ.Choice):
+    componentType = namedtype.NamedTypes(
+        namedtype.NamedType('commonName', char.UTF8String()),
+        namedtype.NamedType('serial', univ.Integer()),
+    )
+
+
+class TbsCertificate(univ.Sequence):
+    componentType = namedtype.NamedTypes(
+        namedtype.DefaultedNamedType('version', univ.Integer(0).subtype(
+            explicitTag=tag.Tag(tag.tagClassContext, tag.tagFormatSimple, 0))),
+        namedtype.NamedType('serialNumber', univ.Integer()),
+        namedtype.NamedType('signature', AlgorithmIdentifier()),
+        namedtype.NamedType('subjects', univ.SequenceOf(Name())),
+        namedtype.NamedType('issuer', Name().subtype(
+            explicitTag=tag.Tag(tag.tagClassContext, tag.tagFormatConstructed, 1))),
+        namedtype.OptionalNamedType('extensions', univ.OctetString().subtype(
+            implicitTag=tag.Tag(tag.tagClassContext, tag.tagFormatSimple, 3))),
+    )
+
+
+class Certificate(univ.Sequence):
+    componentType = namedtype.NamedTypes(
+        namedtype.NamedType('tbsCertificate', TbsCertificate()),
+        namedtype.NamedType('signatureValue', univ.BitString()),
+    )
+
+
+def _create_certificate(version=2):
+    certificate = Certificate()
+
+    tbs_certificate = certificate['tbsCertificate']
+    tbs_certificate['version'] = version
+    tbs_certificate['serialNumber'] = 2 ** 64
+    tbs_certificate['signature']['algorithm'] = '1.2.840.113549.1.1.11'
+    tbs_certificate['subjects'].append(Name())
+    tbs_certificate['subjects'][0]['commonName'] = 'a'
+    tbs_certificate['subjects'].append(Name())
+    tbs_certificate['subjects'][1]['serial'] = 5
+    tbs_certificate['issuer']['commonName'] = 'b'
+
+    certificate['signatureValue'] = univ.BitString(hexValue='abcd')
+
+    return certificate
+
+
+SUBSTRATE = encode(_create_certificate())
+
+
+@pytest.mark.parametrize('path', [
+    ('tbsCertificate',),
+    ('tbsCertificate', 'version'),
+    ('tbsCertificate', 'serialNumber'),
+    ('tbsCertificate', 'signature', 'algorithm'),
+    ('tbsCertificate', 'subjects', 0),
+    ('tbsCertificate', 'subjects', 1, 'serial'),
+    ('tbsCertificate', 'issuer'),
+    ('tbsCertificate', 'issuer', 'commonName'),
+    ('signatureValue',),
+])
+def test_component(path):
+    positions = {}
+
+    decoded, _ = decode_der(SUBSTRATE, Certificate(), positions=positions)
+
+    component, position = decode_der_path(SUBSTRATE, Certificate(), path)
+
+    expected = decoded
+
+    for segment in path:
+        expected = expected[segment]
+
+    assert component == expected
+    assert position == positions[path]
+
+
+@pytest.mark.parametrize('path', [
+    ('tbsCertificate', 'extensions'),
+    ('tbsCertificate', 'signature', 'parameters'),
+    ('tbsCertificate', 'subjects', 2),
+    ('tbsCertificate', 'subjects', 0, 'serial'),
+    ('tbsCertificate', 'issuer', 'serial'),
+])
+def test_absent_component(path):
+    assert decode_der_path(SUBSTRATE, Certificate(), path) is None
+
+
+def test_absent_defaulted_component():
+    substrate = encode(_create_certificate(version=0))
+
+    decoded, _ = decode_der(substrate, Certificate())
+    component, position = decode_der_path(substrate, Certificate(), ('tbsCertificate', 'version'))
+
+    assert component == decoded['tbsCertificate']['version'] == 0
+    assert position is None
+    assert decode_der_path(substrate, Certificate(), ('tbsCertificate', 'serialNumber'))[0] == 2 ** 64
+
+
+def test_skipped_components_are_not_decoded():
+    # the serial number is not minimally encoded, which is only detected if it is decoded
+    substrate = bytes.fromhex('3011300a020300000102010130003000030100')
+
+    class Partial(univ.Sequence):
+        componentType = namedtype.NamedTypes(
+            namedtype.NamedType('tbsCertificate', univ.Sequence(componentType=namedtype.NamedTypes(
+                namedtype.NamedType('serialNumber', univ.Integer()),
+                namedtype.NamedType('version', univ.Integer()),
+                namedtype.NamedType('signature', univ.Sequence()),
+            ))),
+            namedtype.NamedType('signatureValue', univ.Sequence()),
+            namedtype.NamedType('bits', univ.BitString()),
+        )
+
+    component, position = decode_der_path(substrate, Partial(), ('tbsCertificate', 'version'))
+
+    assert component == 1
+    assert position.offset == 9
+
+    with pytest.raises(PyAsn1Error):
+        decode_der_path(substrate, Partial(), ('tbsCertificate', 'serialNumber'))
+
+
+@pytest.mark.parametrize('substrate_hex', [
+    # truncated sibling
+    '30053006020101',
+    # trailing octets
+    '3000ff',
+])
+def test_framing_is_checked(substrate_hex):
+    with pytest.raises(PyAsn1Error):
+        decode_der_path(bytes.fromhex(substrate_hex), Certificate(), ('signatureValue',))
+
+
+@pytest.mark.parametrize('path', [
+    ('unknown',),
+    (0,),
+    ('tbsCertificate', 'serialNumber', 'value'),
+    ('tbsCertificate', 'subjects', 'first'),
+])
+def test_invalid_path(path):
+    with pytest.raises(PyAsn1Error):
+        decode_der_path(SUBSTRATE, Certificate(), path)
@@ -0,0 +1,85 @@
+import binascii
+
+from pyasn1.type import univ, namedtype, char, tag
+
+from pyasn1_fasder import decode_der
+
+
+class PositionChoice(univ.Choice):
+    pass
+
+
+PositionChoice.componentType = namedtype.NamedTypes(
+    namedtype.NamedType('printable', char.PrintableString()),
+    namedtype.NamedType('tagged', univ.Integer().subtype(
+        explicitTag=tag.Tag(tag.tagClassContext, tag.tagFormatSimple, 1))),
+)
+
+
+class PositionStrings(univ.SequenceOf):
+    pass
+
+
+PositionStrings.componentType = char.PrintableString()
+
+
+class PositionSequence(univ.Sequence):
+    pass
+
+
+PositionSequence.componentType = namedtype.NamedTypes(
+    namedtype.DefaultedNamedType('version', univ.Integer(0).subtype(
+        explicitTag=tag.Tag(tag.tagClassContext, tag.tagFormatSimple, 0))),
+    namedtype.OptionalNamedType('serial', univ.Integer()),
+    namedtype.NamedType('strings', PositionStrings()),
+    namedtype.NamedType('choice', PositionChoice()),
+)
+
+
+def _decode(substrate_hex):
+    positions = {}
+
+    decoded, _ = decode_der(binascii.unhexlify(substrate_hex), asn1Spec=PositionSequence(), positions=positions)
+
+    return decoded, positions
+
+
+def test_positions():
+    decoded, positions = _decode(b'3014A003020102020105300613014113014213024142')
+
+    assert positions[()] == (0, 2, 20)
+    assert positions[('version',)] == (2, 2, 3)
+    assert positions[('serial',)] == (7, 2, 1)
+    assert positions[('strings',)] == (10, 2, 6)
+    assert positions[('strings', 0)] == (12, 2, 1)
+    assert positions[('strings', 1)] == (15, 2, 1)
+    assert positions[('choice',)] == (18, 2, 2)
+    assert positions[('choice', 'printable')] == (18, 2, 2)
+
+
+def test_positions_skipped_optional():
+    _, positions = _decode(b'300B3003130141A104020200FF')
+
+    assert ('version',) not in positions
+    assert ('serial',) not in positions
+    assert positions[('strings',)] == (2, 2, 3)
+    assert positions[('choice',)] == (7, 2, 4)
+    assert positions[('choice', 'tagged')] == (7, 2, 4)
+
+
+def test_position_tlv_range():
+    substrate = binascii.unhexlify(b'3014A003020102020105300613014113014213024142')
+    positions = {}
+
+    decode_der(substrate, asn1Spec=PositionSequence(), positions=positions)
+
+    assert substrate[positions[('strings', 1)].tlv_range] == b'\x13\x01\x42'
+    assert positions[('strings', 1)].value_offset == 17
+    assert positions[('strings', 1)].end == 18
+
+
+def test_decoded_without_positions():
+    for substrate_hex in (b'3014A003020102020105300613014113014213024142', b'300B3003130141A104020200FF'):
+        decoded, _ = _decode(substrate_hex)
+
+        assert decode_der(binascii.unhexlify(substrate_hex), asn1Spec=PositionSequence())[0] == decoded
@@ -0,0 +1,60 @@
+import pytest
+from pyasn1.codec.der import encoder
+from pyasn1.type import base, namedtype, tag, univ
+
+from pyasn1_fasder import decode_der, register_decoder, unregister_decoder
+from pyasn1_fasder.error import RoundTripError
+
+
+class UnsignedCelsius(univ.Integer):
+    typeId = base.Asn1Type.getTypeId()
+    tagSet = tag.initTagSet(tag.Tag(tag.tagClassApplication, tag.tagFormatSimple, 2))
+
+
+@pytest.fixture
+def unsigned_celsius_codec():
+    # decodes negative values incorrectly, as the value octets are not interpreted as two's complement
+    register_decoder(UnsignedCelsius,
+                     lambda asn1Spec, value, tagSet, offset: asn1Spec.clone(int.from_bytes(value, 'big')))
+    encoder.TYPE_MAP[UnsignedCelsius.typeId] = encoder.TYPE_MAP[univ.Integer.typeId]
+
+    yield
+
+    unregister_decoder(UnsignedCelsius)
+    del encoder.TYPE_MAP[UnsignedCelsius.typeId]
+
+
+class Reading(univ.Sequence):
+    componentType = namedtype.NamedTypes(
+        namedtype.NamedType('sensor', univ.Integer()),
+        namedtype.NamedType('temperatures', univ.SequenceOf(UnsignedCelsius())),
+    )
+
+
+def test_identical_encoding(unsigned_celsius_codec):
+    substrate = bytes.fromhex('300b020101300642011442010a')
+
+    decoded, _ = decode_der(bytearray(substrate), Reading(), verifyRoundTrip=True)
+
+    assert list(decoded['temperatures']) == [20, 10]
+
+
+def test_differing_encoding(unsigned_celsius_codec):
+    substrate = bytes.fromhex('300b02010130064201144201ec')
+
+    # the decoding error is not detected without verification
+    decode_der(substrate, Reading())
+
+    with pytest.raises(RoundTripError) as exc_info:
+        decode_der(substrate, Reading(), verifyRoundTrip=True)
+
+    assert exc_info.value.offset == 11
+    assert exc_info.value.path == ('temperatures', 1)
+
+
+def test_positions_are_populated(unsigned_celsius_codec):
+    positions = {}
+
+    decode_der(bytes.fromhex('300b020101300642011442010a'), Reading(), positions=positions, verifyRoundTrip=True)
+
+    assert positions[('temperatures', 1)].offset == 10
@@ -0,0 +1,76 @@
+import sys
+import sysconfig
+from concurrent.futures import ThreadPoolExecutor
+
+import pytest
+from pyasn1.codec.der.encoder import encode
+from pyasn1.type import namedtype, tag, univ
+
+from pyasn1_fasder import decode_der, decode_der_native, register_decoder
+
+
+class Record(univ.Sequence):
+    componentType = namedtype.NamedTypes(
+        namedtype.NamedType('id', univ.ObjectIdentifier()),
+        namedtype.NamedType('number', univ.Integer().subtype(
+            explicitTag=tag.Tag(tag.tagClassContext, tag.tagFormatSimple, 0))),
+        namedtype.NamedType('flags', univ.BitString().subtype(
+            implicitTag=tag.Tag(tag.tagClassApplication, tag.tagFormatSimple, 1))),
+    )
+
+
+def _create_records(count):
+    records = univ.SequenceOf(Record())
+
+    for i in range(count):
+        record = Record()
+        record['id'] = (1, 2, 840, i % 7)
+        record['number'] = i
+        record['flags'] = record['flags'].clone(hexValue='80')
+
+        records.append(record)
+
+    return records
+
+
+def test_concurrent_decoding():
+    records = _create_records(50)
+    substrate = encode(records)
+
+    def decode(i):
+        if i % 2:
+            return decode_der(substrate, univ.SequenceOf(Record()))[0]
+        else:
+            return decode_der_native(substrate, univ.SequenceOf(Record()))
+
+    with ThreadPoolExecutor(max_workers=8) as executor:
+        results = list(executor.map(decode, range(64)))
+
+    for i, result in enumerate(results):
+        if i % 2:
+            assert result == records
+        else:
+            assert [r['number'] for r in result] == list(range(50))
+
+
+def test_concurrent_decoder_registration():
+    class Celsius(univ.Integer):
+        typeId = univ.Integer.getTypeId()
+        tagSet = univ.Integer.tagSet.tagImplicitly(tag.Tag(tag.tagClassPrivate, tag.tagFormatSimple, 5))
+
+    def register_and_decode(i):
+        if i == 0:
+            register_decoder(Celsius, univ.Integer)
+
+        return decode_der(encode(_create_records(1)), univ.SequenceOf(Record()))[0]
+
+    with ThreadPoolExecutor(max_workers=4) as executor:
+        results = list(executor.map(register_and_decode, range(16)))
+
+    assert all(r == results[0] for r in results)
+    assert decode_der(bytes.fromhex('c50114'), Celsius())[0] == 20
+
+
+@pytest.mark.skipif(not sysconfig.get_config_var('Py_GIL_DISABLED'), reason='requires a free-threaded build')
+def test_gil_is_not_enabled():
+    assert not sys._is_gil_enabled()
@@ -0,0 +1,114 @@
+import datetime
+
+import pytest
+from pyasn1.codec.der.encoder import encode
+from pyasn1.error import PyAsn1Error
+from pyasn1.type import useful
+
+from pyasn1_fasder import decode_ber, decode_der, decode_der_native, validate_der
+
+
+UTC = datetime.timezone.utc
+
+
+def _encode(asn1_spec, value):
+    # the pyasn1 DER encoder rejects some of the values that are tested
+    return bytes([asn1_spec.tagSet[0].tagId, len(value)]) + value.encode()
+
+
+@pytest.mark.parametrize('value,pivot_year,expected_year', [
+    ('491231235959Z', 1950, 2049),
+    ('500101000000Z', 1950, 1950),
+    ('491231235959Z', 1900, 1949),
+    ('000229000000Z', 2000, 2000),
+])
+def test_utc_time(value, pivot_year, expected_year):
+    decoded, _ = decode_der(encode(useful.UTCTime(value)), useful.UTCTime(), utcTimePivotYear=pivot_year)
+
+    assert decoded.parsedDateTime.year == expected_year
+    assert decoded.parsedDateTime.tzinfo == UTC
+
+
+@pytest.mark.parametrize('value,expected', [
+    ('20240229120000Z', datetime.datetime(2024, 2, 29, 12, 0, 0, tzinfo=UTC)),
+    ('20240229120000.5Z', datetime.datetime(2024, 2, 29, 12, 0, 0, 500000, tzinfo=UTC)),
+    ('20240229120000.1234567Z', datetime.datetime(2024, 2, 29, 12, 0, 0, 123456, tzinfo=UTC)),
+])
+def test_generalized_time(value, expected):
+    decoded, _ = decode_der(_encode(useful.GeneralizedTime(), value), useful.GeneralizedTime())
+
+    assert decoded.parsedDateTime == expected
+    assert str(decoded) == value
+
+
+def test_native_values():
+    decoded = decode_der_native(encode(useful.UTCTime('491231235959Z')), useful.UTCTime(), utcTimePivotYear=1900)
+
+    assert decoded == datetime.datetime(1949, 12, 31, 23, 59, 59, tzinfo=UTC)
+
+
+@pytest.mark.parametrize('asn1_spec,value', [
+    (useful.UTCTime(), '1901010000Z'),
+    (useful.UTCTime(), '190101000000+0100'),
+    (useful.UTCTime(), '190229000000Z'),
+    (useful.UTCTime(), '191301000000Z'),
+    (useful.GeneralizedTime(), '20190101000060Z'),
+    (useful.GeneralizedTime(), '20190101000000.Z'),
+    (useful.GeneralizedTime(), '20190101000000.50Z'),
+    (useful.GeneralizedTime(), '20190101000000,5Z'),
+    (useful.GeneralizedTime(), '20190101000000'),
+    (useful.GeneralizedTime(), '00000101000000Z'),
+])
+def test_invalid_values(asn1_spec, value):
+    substrate = _encode(asn1_spec, value)
+
+    with pytest.raises(PyAsn1Error):
+        decode_der(substrate, asn1_spec)
+
+    with pytest.raises(PyAsn1Error):
+        decode_der_native(substrate, asn1_spec)
+
+    assert len(validate_der(substrate)) == 1
+
+
+def test_ber_local_time_differential():
+    decoded, deviations = decode_ber(_encode(useful.UTCTime(), '190101000000+0100'), useful.UTCTime())
+
+    assert decoded.parsedDateTime is None
+    assert [d.description for d in deviations] == ['Invalid UTCTIME value']
+
+
+@pytest.mark.parametrize('asn1_spec,value', [
+    (useful.UTCTime(), '1901010000Z'),
+    (useful.UTCTime(), '1901010000-0130'),
+    (useful.GeneralizedTime(), '2019010100Z'),
+    (useful.GeneralizedTime(), '201901010000,5Z'),
+    (useful.GeneralizedTime(), '20190101000000.50Z'),
+    (useful.GeneralizedTime(), '20190101000000'),
+    (useful.GeneralizedTime(), '20190101000000+01'),
+])
+def test_ber_permitted_forms(asn1_spec, value):
+    decoded, deviations = decode_ber(_encode(asn1_spec, value), asn1_spec)
+
+    assert str(decoded) == value
+    assert len(deviations) == 1
+
+
+@pytest.mark.parametrize('asn1_spec,value', [
+    (useful.UTCTime(), 'garbage'),
+    (useful.UTCTime(), '191301000000Z'),
+    (useful.UTCTime(), '190101000000+01'),
+    (useful.GeneralizedTime(), '20190101000060Z'),
+    (useful.GeneralizedTime(), '00000101000000Z'),
+    (useful.GeneralizedTime(), '20190101000000.Z'),
+    (useful.GeneralizedTime(), '20190101000000+2500'),
+])
+def test_ber_invalid_values(asn1_spec, value):
+    with pytest.raises(PyAsn1Error):
+        decode_ber(_encode(asn1_spec, value), asn1_spec)
+
+
+@pytest.mark.parametrize('pivot_year', [-1, 0, 9901, 70000, 2 ** 40])
+def test_invalid_pivot_year(pivot_year):
+    with pytest.raises(ValueError):
+        decode_der(encode(useful.UTCTime('491231235959Z')), useful.UTCTime(), utcTimePivotYear=pivot_year)
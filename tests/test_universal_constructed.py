@@ -115,3 +115,8 @@ def test_sequence_missing_element():
 def test_encoded_default_value():
     with pytest.raises(PyAsn1Error):
         decoded, _ = _wrapper(b'30181301410C0161170D3235313233313233353935395A130142', SequenceTest())
+
+
+def test_encoded_default_value_without_optional():
+    with pytest.raises(PyAsn1Error):
+        decoded, _ = _wrapper(b'3015130141170D3235313233313233353935395A130142', SequenceTest())
@@ -1,9 +1,10 @@
 import binascii
 
 import pytest
+from pyasn1.codec.der.encoder import encode
 from pyasn1.error import PyAsn1Error
 
-from pyasn1.type import univ, char, namedval
+from pyasn1.type import univ, char, namedtype, namedval, tag
 
 from pyasn1_fasder import decode_der
 
@@ -59,6 +60,56 @@ def test_integer_negative_one_leading_all_one_bits():
         decoded, _ = _wrapper(b'0202FFFF', univ.Integer())
 
 
+@pytest.mark.parametrize('value', [
+    0, 127, -128, 128, -129, 2 ** 63 - 1, -2 ** 63, 2 ** 63, -2 ** 63 - 1, 2 ** 100, -2 ** 100,
+])
+def test_integer_boundary_values(value):
+    value_octets = value.to_bytes(max(value, ~value).bit_length() // 8 + 1, 'big', signed=True)
+
+    decoded, _ = _wrapper(binascii.hexlify(bytes([0x02, len(value_octets)]) + value_octets), univ.Integer())
+
+    assert int(decoded) == value
+
+
+def test_integer_small_values_in_sequence_of():
+    value = univ.SequenceOf(univ.Integer())
+    value.extend([0, 1, 0, -1, 1, 300])
+
+    decoded, _ = _wrapper(binascii.hexlify(encode(value)), univ.SequenceOf(univ.Integer()))
+
+    assert [int(v) for v in decoded] == [0, 1, 0, -1, 1, 300]
+
+
+def test_integer_small_values_are_distinct_objects():
+    decoded, _ = _wrapper(b'3006020100020100', univ.SequenceOf(univ.Integer()))
+
+    assert decoded[0] == decoded[1]
+    assert decoded[0] is not decoded[1]
+
+
+class Color(univ.Enumerated):
+    namedValues = namedval.NamedValues(
+        ('red', 0),
+        ('green', 1),
+    )
+
+
+def test_small_values_of_different_types():
+    class Value(univ.Sequence):
+        componentType = namedtype.NamedTypes(
+            namedtype.NamedType('number', univ.Integer()),
+            namedtype.NamedType('color', Color()),
+            namedtype.NamedType('otherColor', Color()),
+        )
+
+    decoded, _ = _wrapper(b'3009020101' + b'0a0101' + b'0a0100', Value())
+
+    assert isinstance(decoded['number'], univ.Integer) and not isinstance(decoded['number'], univ.Enumerated)
+    assert isinstance(decoded['color'], Color)
+    assert str(decoded['color']) == 'green'
+    assert str(decoded['otherColor']) == 'red'
+
+
 def test_bitstring_no_trailer_bit_count():
     with pytest.raises(PyAsn1Error):
         decoded, _ = _wrapper(b'0300', univ.BitString())
@@ -113,6 +164,41 @@ def test_null_too_long():
         decoded, _ = _wrapper(b'0501FF', univ.Null())
 
 
+def test_object_identifier():
+    decoded, _ = _wrapper(b'06092a864886f70d01010b', univ.ObjectIdentifier())
+
+    assert decoded == univ.ObjectIdentifier('1.2.840.113549.1.1.11')
+
+
+def test_object_identifier_repeated_values():
+    value = univ.SequenceOf(univ.ObjectIdentifier())
+    value.extend(['2.5.4.3', '2.5.4.6', '2.5.4.3'])
+
+    for _ in range(2):
+        decoded, _ = _wrapper(binascii.hexlify(encode(value)), univ.SequenceOf(univ.ObjectIdentifier()))
+
+        assert list(decoded) == list(value)
+        assert decoded[0] is not decoded[2]
+
+
+def test_object_identifier_repeated_values_with_subtyped_spec():
+    tagged_spec = univ.ObjectIdentifier().subtype(implicitTag=tag.Tag(tag.tagClassContext, tag.tagFormatSimple, 0))
+
+    _wrapper(b'0603550403', univ.ObjectIdentifier())
+
+    decoded, _ = _wrapper(b'8003550403', tagged_spec)
+
+    assert decoded == (2, 5, 4, 3)
+    assert decoded.tagSet == tagged_spec.tagSet
+
+
+def test_object_identifier_invalid_after_valid_value():
+    _wrapper(b'0603550403', univ.ObjectIdentifier())
+
+    with pytest.raises(PyAsn1Error):
+        _wrapper(b'0603550483', univ.ObjectIdentifier())
+
+
 def test_printablestring():
     decoded, _ = _wrapper(b'1303414243', char.PrintableString())
 
@@ -0,0 +1,74 @@
+import pytest
+
+from pyasn1_fasder import validate_der
+
+
+@pytest.mark.parametrize('substrate_hex', [
+    '3003020105',
+    '3106020101020102',
+    '3008A006130441424344',
+    '0C03E282AC',
+])
+def test_valid(substrate_hex):
+    assert validate_der(bytes.fromhex(substrate_hex)) == []
+
+
+@pytest.mark.parametrize('substrate_hex,offset,description', [
+    ('300402020001', 2, 'Non-minimal INTEGER encoding'),
+    ('3003010101', 2, 'Non-canonical BOOLEAN encoding'),
+    ('30040302010F', 2, 'Non-zero trailer value in BIT STRING'),
+    ('30030501FF', 2, 'Invalid NULL value length'),
+    ('3106020102020101', 0, 'Out of order SET component at index 1'),
+    ('300312017E', 2, 'Invalid character in NumericString value'),
+    ('30031601FF', 2, 'Invalid character in IA5String value'),
+    ('3003130126', 2, 'PRINTABLESTRING'),
+    ('A0053003010101', 4, 'Non-canonical BOOLEAN encoding'),
+])
+def test_findings(substrate_hex, offset, description):
+    findings = validate_der(bytes.fromhex(substrate_hex))
+
+    assert len(findings) == 1
+    assert findings[0].offset == offset
+    assert description in findings[0].description
+
+
+def test_multiple_findings():
+    findings = validate_der(bytes.fromhex('300701010102020001'))
+
+    assert [f.offset for f in findings] == [2, 5]
+
+
+def test_trailing_octets():
+    findings = validate_der(bytes.fromhex('050000'))
+
+    assert len(findings) == 1
+    assert findings[0].offset == 2
+
+
+def test_bad_header():
+    findings = validate_der(bytes.fromhex('30810105'))
+
+    assert len(findings) == 1
+    assert findings[0].offset == 0
+
+
+def _nest(substrate, depth):
+    for _ in range(depth):
+        length = len(substrate).to_bytes(4, 'big').lstrip(b'\x00')
+        length_octets = length if len(substrate) < 0x80 else bytes([0x80 | len(length)]) + length
+
+        substrate = b'\x30' + length_octets + substrate
+
+    return substrate
+
+
+def test_deep_nesting():
+    assert validate_der(_nest(bytes.fromhex('0500'), 200000)) == []
+
+
+def test_deep_nesting_findings_in_order():
+    substrate = _nest(bytes.fromhex('3007010101020200013000'), 200000)
+
+    findings = validate_der(substrate)
+
+    assert [f.offset for f in findings] == [len(substrate) - 9, len(substrate) - 6]
@@ -0,0 +1,80 @@
+import pytest
+from pyasn1.codec.der.encoder import encode
+from pyasn1.error import PyAsn1Error
+from pyasn1.type import char, namedtype, tag, univ
+
+from pyasn1_fasder import decode_der
+
+
+class Name(univ.Choice):
+    componentType = namedtype.NamedTypes(
+        namedtype.NamedType('commonName', char.UTF8String()),
+        namedtype.NamedType('serial', univ.Integer()),
+    )
+
+
+class Certificate(univ.Sequence):
+    componentType = namedtype.NamedTypes(
+        namedtype.DefaultedNamedType('version', univ.Integer(0).subtype(
+            explicitTag=tag.Tag(tag.tagClassContext, tag.tagFormatSimple, 0))),
+        namedtype.NamedType('serialNumber', univ.Integer()),
+        namedtype.NamedType('subjects', univ.SequenceOf(Name())),
+    )
+
+
+def _create_certificate():
+    certificate = Certificate()
+
+    certificate['version'] = 2
+    certificate['serialNumber'] = 5
+    certificate['subjects'].append(Name())
+    certificate['subjects'][0]['commonName'] = 'a'
+
+    return certificate
+
+
+SUBSTRATE = encode(_create_certificate())
+
+
+def test_components_are_visited():
+    visited = []
+
+    def visitor(path, spec, value, offset, tlv):
+        visited.append((path, offset, tlv))
+
+    positions = {}
+
+    decoded, _ = decode_der(SUBSTRATE, Certificate(), positions=positions, visitor=visitor)
+
+    assert [v[0] for v in visited] == [
+        ('version',), ('serialNumber',), ('subjects', 0, 'commonName'), ('subjects', 0), ('subjects',), (),
+    ]
+
+    for path, offset, tlv in visited:
+        assert offset == positions[path].offset
+        assert tlv == SUBSTRATE[positions[path].tlv_range]
+
+
+def test_visited_values():
+    visited = {}
+
+    def visitor(path, spec, value, offset, tlv):
+        visited[path] = (spec, value)
+
+    decoded, _ = decode_der(SUBSTRATE, Certificate(), visitor=visitor)
+
+    assert visited[()][1] is decoded
+    assert visited[('serialNumber',)][1] == 5
+    assert isinstance(visited[('version',)][0], univ.Integer)
+    assert visited[('subjects', 0, 'commonName')][1] == 'a'
+
+
+def test_visitor_aborts_decoding():
+    def visitor(path, spec, value, offset, tlv):
+        if path == ('serialNumber',):
+            raise ValueError('Unexpected serial number')
+
+    with pytest.raises(PyAsn1Error, match='Error visiting "Integer" TLV near substrate offset 7: Unexpected serial number') as e:
+        decode_der(SUBSTRATE, Certificate(), visitor=visitor)
+
+    assert isinstance(e.value.__cause__, ValueError)
@@ -0,0 +1,110 @@
+import warnings
+
+import pytest
+from pyasn1.codec.der.encoder import encode
+from pyasn1.type import namedtype, univ, useful
+
+from pyasn1_fasder import DecodeWarning, IntegerRule, decode_ber, decode_der, decode_der_native
+
+
+class TbsCertificate(univ.Sequence):
+    componentType = namedtype.NamedTypes(
+        namedtype.NamedType('serialNumber', univ.Integer()),
+        namedtype.NamedType('extensions', univ.SequenceOf(univ.Integer())),
+    )
+
+
+def _encode_certificate(serial_number, extension_count=1):
+    tbs_certificate = TbsCertificate()
+    tbs_certificate['serialNumber'] = serial_number
+    tbs_certificate['extensions'].extend(range(extension_count))
+
+    return encode(tbs_certificate)
+
+
+SERIAL_NUMBER_RULES = {('serialNumber',): IntegerRule(positive=True, warn=True)}
+
+
+def _collect_warnings(decode, substrate, asn1Spec, **kwargs):
+    collected = []
+
+    decode(substrate, asn1Spec, warningCallback=collected.append, **kwargs)
+
+    return collected
+
+
+def test_negative_serial_number():
+    collected = _collect_warnings(decode_der, _encode_certificate(-5), TbsCertificate(),
+                                  integerRules=SERIAL_NUMBER_RULES)
+
+    assert len(collected) == 1
+    assert collected[0].description == 'Value is not positive'
+    assert collected[0].offset == 2
+    assert collected[0].path == ('serialNumber',)
+
+
+@pytest.mark.parametrize('serial_number', [1, 5, 2 ** 64])
+def test_positive_serial_number(serial_number):
+    assert _collect_warnings(decode_der, _encode_certificate(serial_number), TbsCertificate(),
+                             integerRules=SERIAL_NUMBER_RULES) == []
+
+
+def test_negative_serial_number_without_rules():
+    assert _collect_warnings(decode_der, _encode_certificate(-5), TbsCertificate()) == []
+
+
+def test_empty_sequence_of():
+    collected = _collect_warnings(decode_der, _encode_certificate(5, extension_count=0), TbsCertificate(),
+                                  nonEmptyComponents=[('extensions',)])
+
+    assert [(w.description, w.offset, w.path) for w in collected] == [('Empty SEQUENCE OF value', 5, ('extensions',))]
+
+
+def test_empty_sequence_of_without_profile():
+    assert _collect_warnings(decode_der, _encode_certificate(5, extension_count=0), TbsCertificate()) == []
+
+
+def test_empty_set_of():
+    collected = _collect_warnings(decode_der, bytes.fromhex('3100'), univ.SetOf(univ.Integer()),
+                                  nonEmptyComponents=[()])
+
+    assert [(w.description, w.path) for w in collected] == [('Empty SET OF value', ())]
+
+
+def test_utc_time_after_2049():
+    substrate = encode(useful.UTCTime('500101000000Z'))
+
+    assert _collect_warnings(decode_der, substrate, useful.UTCTime()) == []
+
+    collected = _collect_warnings(decode_der, substrate, useful.UTCTime(), utcTimePivotYear=2000)
+
+    assert [w.description for w in collected] == ['UTCTime value after 2049']
+
+
+def test_native_and_ber_decoding():
+    substrate = _encode_certificate(-5)
+
+    assert len(_collect_warnings(decode_der_native, substrate, TbsCertificate(), integerRules=SERIAL_NUMBER_RULES)) == 1
+    assert len(_collect_warnings(decode_ber, substrate, TbsCertificate(), integerRules=SERIAL_NUMBER_RULES)) == 1
+
+
+def test_warnings_module():
+    with pytest.warns(DecodeWarning, match='Value is not positive near substrate offset 2'):
+        decode_der(_encode_certificate(-5), TbsCertificate(), integerRules=SERIAL_NUMBER_RULES)
+
+
+def test_warnings_as_errors():
+    with warnings.catch_warnings():
+        warnings.simplefilter('error', DecodeWarning)
+
+        with pytest.raises(DecodeWarning):
+            decode_der(_encode_certificate(-5), TbsCertificate(), integerRules=SERIAL_NUMBER_RULES)
+
+
+def test_callback_exception_is_raised():
+    def callback(warning):
+        raise ValueError(warning.description)
+
+    with pytest.raises(ValueError, match='Value is not positive'):
+        decode_der(_encode_certificate(-5), TbsCertificate(), integerRules=SERIAL_NUMBER_RULES,
+                   warningCallback=callback)